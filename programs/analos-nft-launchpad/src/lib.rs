@@ -1,22 +1,34 @@
 use anchor_lang::prelude::*;
-use anchor_lang::solana_program::{keccak, program::invoke_signed, system_instruction};
+use anchor_lang::solana_program::{
+    ed25519_program, keccak, program::invoke_signed, system_instruction, sysvar,
+};
 use anchor_spl::{
-    associated_token::AssociatedToken,
-    token::{mint_to, Mint, MintTo, Token, TokenAccount},
+    associated_token::{self, AssociatedToken, Create},
+    token::{self, mint_to, Burn, InitializeMint2, Mint, MintTo, SetAuthority, Token, TokenAccount, Transfer},
+    token::spl_token::instruction::AuthorityType,
 };
 use mpl_bubblegum::{
-    cpi::accounts::MintV1,
-    cpi::mint_v1,
-    program::BubblegumProgram,
-    state::MetadataArgs,
-    state::Creator,
-    state::Collection,
-    state::TokenProgramVersion,
-    state::TokenStandard,
+    accounts::TreeConfig,
+    instructions::{CreateTreeConfigCpiBuilder, MintToCollectionV1CpiBuilder, UpdateMetadataCpiBuilder},
+    types::{Collection, Creator, MetadataArgs, TokenProgramVersion, TokenStandard, UpdateArgs},
+    utils::get_asset_id,
 };
+use mpl_token_metadata::accounts::Metadata;
 use spl_account_compression::{
     program::SplAccountCompression,
-    state::ConcurrentMerkleTreeAccount,
+    state::CONCURRENT_MERKLE_TREE_HEADER_SIZE_V1,
+};
+use analos_price_oracle::{PriceOracle, DECIMALS_LOS, MAX_PRICE_STALENESS_SECONDS};
+use analos_metadata::{
+    cpi::accounts::{CreateMetadata, UpdateMetadata as UpdateStandardMetadata},
+    cpi::{create_metadata, update_metadata as update_standard_metadata},
+    program::AnalosMetadata,
+};
+use analos_token_launch::{
+    cpi::accounts::MintTokensForNFT,
+    cpi::mint_tokens_for_nft,
+    program::AnalosTokenLaunch,
+    TokenLaunchConfig,
 };
 
 // Security.txt implementation for program verification
@@ -39,47 +51,159 @@ security_txt! {
 
 declare_id!("5gmaywNK418QzG7eFA7qZLJkCGS8cfcPtm4b2RZQaJHT");
 
-/// Royalty basis points (500 = 5%)
-pub const ROYALTY_BASIS_POINTS: u16 = 500;
+/// Royalty constants
+pub const MAX_ROYALTY_BASIS_POINTS: u16 = 10000;
+pub const MAX_CREATORS: usize = 4; // Bubblegum allows 5; one slot is the verified config PDA
+
+/// Fee system constants (recipients and shares live in PlatformConfig)
+pub const MAX_FEE_RECIPIENTS: usize = 5;
+pub const MAX_TOTAL_FEE_BASIS_POINTS: u16 = 1000; // 10% cap on total mint fees
+pub const MAX_REFERRAL_BASIS_POINTS: u16 = 5000; // Referrers get at most half the creator payment
 
-/// Fee system constants
-pub const PLATFORM_FEE_BASIS_POINTS: u16 = 250; // 2.5%
-pub const BUYBACK_FEE_BASIS_POINTS: u16 = 150; // 1.5%
-pub const DEV_FEE_BASIS_POINTS: u16 = 100; // 1.0%
-pub const TOTAL_FEE_BASIS_POINTS: u16 = 500; // 5.0% total
+/// Collection metadata bounds (CollectionConfig `#[max_len]`)
+pub const MAX_NAME_LENGTH: usize = 32;
+pub const MAX_SYMBOL_LENGTH: usize = 10;
+pub const MAX_URI_LENGTH: usize = 200;
 
-/// Fee recipient addresses
-pub const PLATFORM_WALLET: &str = "7axzrUvuYZ32bKLS5eVZC6okfJNVvz33eQc4RLNRpQPi"; // Platform revenue wallet
-pub const BUYBACK_WALLET: &str = "9ReqU29vEXtnQfMUp74CyfPwnKRUAKSDBzo8C62p2jo2"; // $LOL buyback wallet
-pub const DEV_WALLET: &str = "GMYuGbRtSaPxviMXcnU8GLh6Yt6azxw1Y6JHNesU8MVr"; // Developer maintenance wallet
+/// Creator payout constants
+pub const MAX_PAYOUT_WALLETS: usize = 5;
 
 /// Merkle tree constants
-pub const MAX_DEPTH: u32 = 14; // Supports up to 16,384 NFTs
-pub const MAX_BUFFER_SIZE: u32 = 64;
+pub const MAX_TREE_DEPTH: u32 = 30; // Bubblegum's deepest supported tree
+pub const MAX_CANOPY_DEPTH: u32 = 17;
+pub const MAX_TREES: usize = 8; // Trees a collection can mint into, filled in order
+
+/// Reveal seed constants
+pub const SEED_SLOT_DELAY: u64 = 10; // Slots between the secret reveal and the mixed-in slot hash
+pub const SLOT_HASHES_MAX_ENTRIES: u64 = 512; // Slots retained by the SlotHashes sysvar; finalize within this window
+
+/// Mint guard constants (ed25519 program instruction layout)
+pub const ED25519_OFFSETS_START: usize = 2; // u8 signature count + u8 padding
+pub const ED25519_OFFSETS_SIZE: usize = 14; // Seven u16 offsets per signature
+pub const MINT_GUARD_MESSAGE_LEN: usize = 72; // collection (32) + minter (32) + expiry (i64)
+
+/// Fixed-point scale for exponential bonding curves
+pub const CURVE_SCALE: u128 = 1_000_000_000_000;
+
+/// Fixed-point scale for Dutch auction rebate clearing units
+pub const REBATE_SCALE: u128 = 1_000_000_000_000;
+
+/// Maximum collections tracked in one authority's registry
+pub const MAX_COLLECTIONS_PER_AUTHORITY: usize = 50;
+
+/// Mint phase constants
+pub const MAX_MINT_PHASES: usize = 5;
+
+/// Maximum NFTs per mint_batch call (bounded by compute limits)
+pub const MAX_BATCH_MINT: u64 = 10;
 
 #[program]
 pub mod analos_nft_launchpad {
     use super::*;
 
     /// Initialize a new collection with Merkle tree for compressed NFTs
+    ///
+    /// `collection_id` must be the next index in the authority's registry.
+    /// `seed_commitment` is `keccak(secret)`; the secret is revealed with the
+    /// collection and mixed with a future slot hash into the reveal seed.
+    ///
+    /// `mint_mode` picks compressed (Bubblegum) or standard SPL NFTs. Compressed
+    /// collections pass `tree_params` and a `merkle_tree` pre-allocated to that
+    /// size; the tree is created here with the collection config PDA as tree creator.
+    ///
+    /// `creators` takes 1-4 distinct wallets: Bubblegum allows 5 creators and the
+    /// collection config PDA always fills one slot as the verified signing creator.
     pub fn initialize_collection(
         ctx: Context<InitializeCollection>,
+        collection_id: u64,
         collection_name: String,
         collection_symbol: String,
         max_supply: u64,
+        reserved_supply: u64,
         price_lamports: u64,
         reveal_threshold: u64,
         placeholder_uri: String,
+        royalty_basis_points: u16,
+        creators: Vec<CreatorShare>,
+        seed_commitment: [u8; 32],
+        mint_mode: MintMode,
+        tree_params: Option<TreeParams>,
     ) -> Result<()> {
+        require!(max_supply > 0, ErrorCode::InvalidMaxSupply);
+        require!(reserved_supply <= max_supply, ErrorCode::InvalidReservedSupply);
         require!(
-            max_supply <= 16384,
-            ErrorCode::InvalidMaxSupply
+            item_name_fits(&collection_name, max_supply),
+            ErrorCode::NameTooLong
         );
+        require!(collection_symbol.len() <= MAX_SYMBOL_LENGTH, ErrorCode::SymbolTooLong);
+        require!(placeholder_uri.len() <= MAX_URI_LENGTH, ErrorCode::UriTooLong);
+        validate_creators(
+            royalty_basis_points,
+            &creators,
+            &ctx.accounts.collection_config.key(),
+        )?;
+        // Only paid mints count towards the reveal threshold
         require!(
-            reveal_threshold <= max_supply && reveal_threshold > 0,
+            reveal_threshold <= max_supply - reserved_supply && reveal_threshold > 0,
             ErrorCode::InvalidThreshold
         );
 
+        let registry = &mut ctx.accounts.collection_registry;
+        require!(
+            collection_id == registry.collection_count,
+            ErrorCode::InvalidCollectionId
+        );
+        require!(
+            registry.collections.len() < MAX_COLLECTIONS_PER_AUTHORITY,
+            ErrorCode::TooManyCollections
+        );
+        registry.authority = ctx.accounts.authority.key();
+        registry.collection_count += 1;
+        registry.collections.push(ctx.accounts.collection_config.key());
+
+        // The config signs with seeds derived from these two fields
+        let config = &mut ctx.accounts.collection_config;
+        config.collection_id = collection_id;
+        config.seed_authority = ctx.accounts.authority.key();
+
+        // Compressed collections create their first tree with the collection
+        // config PDA as tree creator
+        let mut merkle_trees = Vec::new();
+        if mint_mode == MintMode::Compressed {
+            let tree_params = tree_params.ok_or(ErrorCode::InvalidTreeParams)?;
+            let merkle_tree = required_account(&ctx.accounts.merkle_tree)?;
+
+            let seeds = ctx
+                .accounts
+                .collection_config
+                .signer_seeds(ctx.bumps.collection_config);
+            let signer_seeds = &[&seeds.as_seeds()[..]];
+
+            let tree_accounts = CreateTreeAccounts {
+                bubblegum_program: required_account(&ctx.accounts.bubblegum_program)?,
+                tree_config: required_account(&ctx.accounts.tree_config)?,
+                merkle_tree: merkle_tree.clone(),
+                payer: ctx.accounts.payer.to_account_info(),
+                tree_creator: ctx.accounts.collection_config.to_account_info(),
+                log_wrapper: required_account(&ctx.accounts.log_wrapper)?,
+                compression_program: required_account(&ctx.accounts.compression_program)?,
+                system_program: ctx.accounts.system_program.to_account_info(),
+            };
+            create_collection_tree(tree_accounts, signer_seeds, &tree_params)?;
+
+            emit!(TreeRegisteredEvent {
+                collection_config: ctx.accounts.collection_config.key(),
+                merkle_tree: merkle_tree.key(),
+                tree_index: 0,
+                max_depth: tree_params.max_depth,
+                max_buffer_size: tree_params.max_buffer_size,
+                canopy_depth: tree_params.canopy_depth,
+                timestamp: Clock::get()?.unix_timestamp,
+            });
+
+            merkle_trees.push(merkle_tree.key());
+        }
+
         let config = &mut ctx.accounts.collection_config;
         config.authority = ctx.accounts.authority.key();
         config.max_supply = max_supply;
@@ -92,19 +216,50 @@ pub mod analos_nft_launchpad {
         config.collection_name = collection_name;
         config.collection_symbol = collection_symbol;
         config.placeholder_uri = placeholder_uri;
+        config.revealed_base_uri = String::new();
+        config.mint_phases = Vec::new();
+        config.fee_override = None;
+        config.payment_mint = None;
+        config.payment_price = 0;
+        config.pricing_mode = PricingMode::Fixed;
+        config.royalty_basis_points = royalty_basis_points;
+        config.creators = creators;
+        config.last_mint_price = 0;
+        config.auction_net_paid = 0;
+        config.auction_clearing_units = 0;
+        config.auction_rebates_paid = 0;
+        config.reveal_deadline = None;
+        config.escrow_total = 0;
+        config.refunds_paid = 0;
+        config.pending_fee_lamports = 0;
+        config.pending_referral_lamports = 0;
+        config.token_gate = None;
+        config.mint_guard_signer = None;
+        config.referral_bps = 0;
+        config.payout_split = Vec::new();
+        config.is_locked = false;
+        config.token_launch_config = None;
+
+        // Reveal seed is only derived once the committed secret is revealed
+        config.global_seed = [0; 32];
+        config.seed_commitment = seed_commitment;
+        config.seed_secret = [0; 32];
+        config.seed_slot = 0;
+        config.is_seed_final = false;
+        config.mint_mode = mint_mode;
+        config.merkle_trees = merkle_trees;
+        config.active_tree = 0;
+        config.reserved_supply = reserved_supply;
+        config.reserved_minted = 0;
+        config.pending_authority = None;
 
-        // Generate random global seed for reveal
         let clock = Clock::get()?;
-        let seed_data = [
-            clock.unix_timestamp.to_le_bytes(),
-            clock.slot.to_le_bytes(),
-            ctx.accounts.authority.key().to_bytes(),
-        ];
-        config.global_seed = keccak::hash(&seed_data).to_bytes();
 
         emit!(CollectionInitializedEvent {
             collection_config: config.key(),
             authority: config.authority,
+            collection_id: config.collection_id,
+            mint_mode,
             max_supply: config.max_supply,
             price_lamports: config.price_lamports,
             reveal_threshold: config.reveal_threshold,
@@ -117,167 +272,358 @@ pub mod analos_nft_launchpad {
         Ok(())
     }
 
-    /// Mint a compressed placeholder NFT (mystery box)
-    pub fn mint_placeholder(ctx: Context<MintPlaceholder>) -> Result<()> {
-        let config = &mut ctx.accounts.collection_config;
-
-        // Validations
-        require!(!config.is_paused, ErrorCode::CollectionPaused);
+    /// Create and register an additional Merkle tree for the collection
+    ///
+    /// Mints fill trees in registration order, moving on once a tree is full.
+    pub fn register_tree(ctx: Context<RegisterTree>, tree_params: TreeParams) -> Result<()> {
         require!(
-            config.current_supply < config.max_supply,
-            ErrorCode::SoldOut
+            ctx.accounts.collection_config.mint_mode == MintMode::Compressed,
+            ErrorCode::UnsupportedMintMode
+        );
+        require!(
+            ctx.accounts.collection_config.merkle_trees.len() < MAX_TREES,
+            ErrorCode::TooManyTrees
         );
 
-        let mint_index = config.current_supply;
+        let config = &ctx.accounts.collection_config;
+        let seeds = config.signer_seeds(ctx.bumps.collection_config);
+        let signer_seeds = &[&seeds.as_seeds()[..]];
 
-        // Calculate fee distribution
-        let total_fee = config.price_lamports * TOTAL_FEE_BASIS_POINTS as u64 / 10000;
-        let platform_fee = config.price_lamports * PLATFORM_FEE_BASIS_POINTS as u64 / 10000;
-        let buyback_fee = config.price_lamports * BUYBACK_FEE_BASIS_POINTS as u64 / 10000;
-        let dev_fee = config.price_lamports * DEV_FEE_BASIS_POINTS as u64 / 10000;
-        let creator_payment = config.price_lamports - total_fee;
+        let tree_accounts = CreateTreeAccounts {
+            bubblegum_program: ctx.accounts.bubblegum_program.to_account_info(),
+            tree_config: ctx.accounts.tree_config.to_account_info(),
+            merkle_tree: ctx.accounts.merkle_tree.to_account_info(),
+            payer: ctx.accounts.authority.to_account_info(),
+            tree_creator: config.to_account_info(),
+            log_wrapper: ctx.accounts.log_wrapper.to_account_info(),
+            compression_program: ctx.accounts.compression_program.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+        };
+        create_collection_tree(tree_accounts, signer_seeds, &tree_params)?;
 
-        // Transfer payment to collection creator (95%)
-        let creator_transfer_ix =
-            system_instruction::transfer(&ctx.accounts.payer.key(), &config.key(), creator_payment);
-        invoke_signed(
-            &creator_transfer_ix,
-            &[
-                ctx.accounts.payer.to_account_info(),
-                config.to_account_info(),
-                ctx.accounts.system_program.to_account_info(),
-            ],
-            &[],
-        )?;
+        let config = &mut ctx.accounts.collection_config;
+        config.merkle_trees.push(ctx.accounts.merkle_tree.key());
 
-        // Transfer platform fee (2.5%)
-        if platform_fee > 0 {
-            let platform_wallet: Pubkey = PLATFORM_WALLET.parse().unwrap();
-            let platform_transfer_ix = system_instruction::transfer(
-                &ctx.accounts.payer.key(),
-                &platform_wallet,
-                platform_fee,
-            );
-            invoke_signed(
-                &platform_transfer_ix,
-                &[
-                    ctx.accounts.payer.to_account_info(),
-                    ctx.accounts.system_program.to_account_info(),
-                ],
-                &[],
-            )?;
-        }
+        emit!(TreeRegisteredEvent {
+            collection_config: config.key(),
+            merkle_tree: ctx.accounts.merkle_tree.key(),
+            tree_index: (config.merkle_trees.len() - 1) as u8,
+            max_depth: tree_params.max_depth,
+            max_buffer_size: tree_params.max_buffer_size,
+            canopy_depth: tree_params.canopy_depth,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
 
-        // Transfer buyback fee (1.5%)
-        if buyback_fee > 0 {
-            let buyback_wallet: Pubkey = BUYBACK_WALLET.parse().unwrap();
-            let buyback_transfer_ix =
-                system_instruction::transfer(&ctx.accounts.payer.key(), &buyback_wallet, buyback_fee);
-            invoke_signed(
-                &buyback_transfer_ix,
-                &[
-                    ctx.accounts.payer.to_account_info(),
-                    ctx.accounts.system_program.to_account_info(),
-                ],
-                &[],
-            )?;
+        msg!("Registered tree #{}: {} (depth {}, buffer {}, canopy {})", 
+            config.merkle_trees.len() - 1, ctx.accounts.merkle_tree.key(),
+            tree_params.max_depth, tree_params.max_buffer_size, tree_params.canopy_depth);
+
+        Ok(())
+    }
+
+    /// Initialize the global platform fee configuration
+    ///
+    /// Only the program's upgrade authority can initialize it and become `admin`.
+    pub fn initialize_platform_config(
+        ctx: Context<InitializePlatformConfig>,
+        fee_recipients: Vec<FeeRecipient>,
+    ) -> Result<()> {
+        validate_fee_recipients(&fee_recipients)?;
+
+        let platform_config = &mut ctx.accounts.platform_config;
+        platform_config.admin = ctx.accounts.admin.key();
+        platform_config.fee_recipients = fee_recipients;
+
+        emit!(PlatformConfigUpdatedEvent {
+            admin: platform_config.admin,
+            fee_recipients: platform_config.fee_recipients.clone(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Platform config initialized with {} fee recipients", 
+            platform_config.fee_recipients.len());
+
+        Ok(())
+    }
+
+    /// Update the platform admin and/or the default fee recipients
+    pub fn update_platform_config(
+        ctx: Context<UpdatePlatformConfig>,
+        new_admin: Option<Pubkey>,
+        new_fee_recipients: Option<Vec<FeeRecipient>>,
+    ) -> Result<()> {
+        let platform_config = &mut ctx.accounts.platform_config;
+
+        if let Some(fee_recipients) = new_fee_recipients {
+            validate_fee_recipients(&fee_recipients)?;
+            platform_config.fee_recipients = fee_recipients;
         }
 
-        // Transfer dev fee (1.0%)
-        if dev_fee > 0 {
-            let dev_wallet: Pubkey = DEV_WALLET.parse().unwrap();
-            let dev_transfer_ix =
-                system_instruction::transfer(&ctx.accounts.payer.key(), &dev_wallet, dev_fee);
-            invoke_signed(
-                &dev_transfer_ix,
-                &[
-                    ctx.accounts.payer.to_account_info(),
-                    ctx.accounts.system_program.to_account_info(),
-                ],
-                &[],
-            )?;
+        if let Some(admin) = new_admin {
+            platform_config.admin = admin;
         }
 
-        // Create metadata for compressed NFT
-        let metadata = MetadataArgs {
-            name: format!("{} Mystery #{}", config.collection_name, mint_index),
-            symbol: config.collection_symbol.clone(),
-            uri: config.placeholder_uri.clone(),
-            seller_fee_basis_points: ROYALTY_BASIS_POINTS,
-            creators: vec![Creator {
-                address: config.authority,
-                verified: false,
-                share: 100,
-            }],
-            edition_nonce: None,
-            uses: None,
-            collection: Some(Collection {
-                verified: false,
-                key: config.collection_mint,
-            }),
-            token_program_version: TokenProgramVersion::Original,
-            token_standard: Some(TokenStandard::NonFungible),
-        };
+        emit!(PlatformConfigUpdatedEvent {
+            admin: platform_config.admin,
+            fee_recipients: platform_config.fee_recipients.clone(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
 
-        // Mint compressed NFT using Bubblegum
-        let mint_v1_accounts = MintV1 {
-            tree_config: ctx.accounts.tree_config.to_account_info(),
-            leaf_owner: ctx.accounts.payer.key(),
-            leaf_delegate: ctx.accounts.payer.key(),
-            merkle_tree: ctx.accounts.merkle_tree.to_account_info(),
-            payer: ctx.accounts.payer.to_account_info(),
-            tree_creator_or_delegate: ctx.accounts.tree_creator.to_account_info(),
-            log_wrapper: ctx.accounts.log_wrapper.to_account_info(),
-            compression_program: ctx.accounts.compression_program.to_account_info(),
-            system_program: ctx.accounts.system_program.to_account_info(),
-        };
+        msg!("Platform config updated: admin={}, {} fee recipients", 
+            platform_config.admin, platform_config.fee_recipients.len());
 
-        let mint_v1_ctx = CpiContext::new(
-            ctx.accounts.bubblegum_program.to_account_info(),
-            mint_v1_accounts,
-        );
+        Ok(())
+    }
 
-        mint_v1(mint_v1_ctx, metadata)?;
+    /// Set or clear a per-collection fee override (platform admin only)
+    pub fn set_collection_fee_override(
+        ctx: Context<SetCollectionFeeOverride>,
+        fee_override: Option<Vec<FeeRecipient>>,
+    ) -> Result<()> {
+        if let Some(fee_recipients) = &fee_override {
+            validate_fee_recipients(fee_recipients)?;
+        }
 
-        config.current_supply += 1;
+        let config = &mut ctx.accounts.collection_config;
+        config.fee_override = fee_override;
 
-        emit!(MintEvent {
-            mint_index,
-            minter: ctx.accounts.payer.key(),
-            merkle_tree: ctx.accounts.merkle_tree.key(),
+        emit!(CollectionFeeOverrideEvent {
+            collection_config: config.key(),
+            fee_override: config.fee_override.clone(),
             timestamp: Clock::get()?.unix_timestamp,
         });
 
-        emit!(FeeCollectionEvent {
-            mint_index,
-            total_payment: config.price_lamports,
-            creator_payment,
-            platform_fee,
-            buyback_fee,
-            dev_fee,
+        msg!("Fee override for {} set: {}", 
+            config.collection_name, config.fee_override.is_some());
+
+        Ok(())
+    }
+
+    /// Mint a compressed placeholder NFT (mystery box)
+    ///
+    /// When the collection has mint phases, the active phase sets the price and
+    /// per-wallet limit, and `allowlist_proof` must prove the minter is on the
+    /// phase allowlist if it has one.
+    ///
+    /// Collections with a `payment_mint` are paid in that SPL token at
+    /// `payment_price`; phases still gate timing, allowlists and wallet caps.
+    /// USD-pegged collections must pass the `price_oracle` account.
+    /// Collections linked to a token launch must pass its config, mint, escrow
+    /// and program so launch tokens are minted with every NFT.
+    ///
+    /// The last remaining accounts are the uninitialized MintRecord PDAs
+    /// (`[b"mint_record", collection_config, mint_index]`), one per item minted.
+    ///
+    /// Remaining accounts: one writable account per fee recipient, in the order of
+    /// the collection fee override or, if unset, the platform config. These are
+    /// the recipient wallets, or their token accounts for SPL payments.
+    ///
+    /// Token-gated collections append the payer's gating token account, then its
+    /// metadata account (verified-collection gates), then the gating mint (burn)
+    /// or the authority's token account (transfer) when the gate is consumed.
+    ///
+    /// Guarded collections require the preceding instruction to be an ed25519
+    /// program verification of `mint_guard_signer`'s signature over
+    /// `collection_config || payer || expiry (i64 LE)`, with `instructions_sysvar` passed.
+    ///
+    /// An optional `referrer` (with `referral_stats`, and `referrer_token_account`
+    /// for SPL payments) earns `referral_bps` of the creator payment.
+    pub fn mint_placeholder<'info>(
+        ctx: Context<'_, '_, '_, 'info, MintPlaceholder<'info>>,
+        allowlist_proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        let (mint_index, total_payment) =
+            process_mint(
+                ctx.accounts,
+                ctx.remaining_accounts,
+                &allowlist_proof,
+                1,
+                ctx.bumps.collection_config,
+            )?;
+
+        msg!("Minted compressed NFT #{} for {} - Paid: {}", 
+            mint_index, ctx.accounts.payer.key(), total_payment);
+
+        Ok(())
+    }
+
+    /// Mint several compressed placeholder NFTs in one transaction
+    ///
+    /// Same accounts and rules as `mint_placeholder`; fees are aggregated into a
+    /// single transfer per recipient. Quantity is capped by compute limits.
+    pub fn mint_batch<'info>(
+        ctx: Context<'_, '_, '_, 'info, MintPlaceholder<'info>>,
+        quantity: u64,
+        allowlist_proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        require!(
+            quantity > 0 && quantity <= MAX_BATCH_MINT,
+            ErrorCode::InvalidQuantity
+        );
+
+        let (first_index, total_payment) =
+            process_mint(
+                ctx.accounts,
+                ctx.remaining_accounts,
+                &allowlist_proof,
+                quantity,
+                ctx.bumps.collection_config,
+            )?;
+
+        emit!(BatchMintEvent {
+            collection_config: ctx.accounts.collection_config.key(),
+            minter: ctx.accounts.payer.key(),
+            first_index,
+            quantity,
+            total_payment,
             timestamp: Clock::get()?.unix_timestamp,
         });
 
-        msg!("Minted compressed NFT #{} for {} - Fees: Platform: {} lamports, Buyback: {} lamports, Dev: {} lamports", 
-            mint_index, ctx.accounts.payer.key(), platform_fee, buyback_fee, dev_fee);
+        msg!("Batch minted {} compressed NFTs (#{} - #{}) for {} - Paid: {}", 
+            quantity, first_index, first_index + quantity - 1, ctx.accounts.payer.key(), total_payment);
+
+        Ok(())
+    }
+
+    /// Mint reserved placeholders to `recipients` without payment (authority only)
+    ///
+    /// Draws from the `reserved_supply` budget declared at initialization and
+    /// counts against `max_supply`. Reserved mints don't count towards the
+    /// reveal threshold and don't fund a linked token launch. Compressed
+    /// collections only.
+    ///
+    /// Remaining accounts: the recipient wallets in order, then one uninitialized
+    /// MintRecord PDA per recipient.
+    pub fn mint_reserved<'info>(
+        ctx: Context<'_, '_, '_, 'info, MintReserved<'info>>,
+        recipients: Vec<Pubkey>,
+    ) -> Result<()> {
+        let config = &ctx.accounts.collection_config;
+        let quantity = recipients.len() as u64;
+        let now = Clock::get()?.unix_timestamp;
+
+        require!(config.mint_mode == MintMode::Compressed, ErrorCode::UnsupportedMintMode);
+        // Rarity becomes predictable once the secret is revealed
+        require!(!config.is_revealed, ErrorCode::AlreadyRevealed);
+        require!(
+            quantity > 0 && quantity <= MAX_BATCH_MINT,
+            ErrorCode::InvalidQuantity
+        );
+        require!(
+            config.reserved_minted + quantity <= config.reserved_supply,
+            ErrorCode::ReservedSupplyExhausted
+        );
+        require!(
+            config.current_supply + quantity <= config.max_supply,
+            ErrorCode::SoldOut
+        );
+        require!(!refunds_open(config, now), ErrorCode::RevealDeadlinePassed);
+        require!(
+            ctx.remaining_accounts.len() == recipients.len() * 2,
+            ErrorCode::InvalidRecipients
+        );
+
+        let fills_tree = check_active_tree(
+            config,
+            &ctx.accounts.merkle_tree,
+            &ctx.accounts.tree_config,
+            quantity,
+        )?;
+
+        let first_index = config.current_supply;
+        let (recipient_accounts, record_accounts) =
+            ctx.remaining_accounts.split_at(recipients.len());
+
+        for (mint_index, (recipient, (recipient_info, record_info))) in (first_index..).zip(
+            recipients
+                .iter()
+                .zip(recipient_accounts.iter().zip(record_accounts.iter())),
+        ) {
+            require!(recipient_info.key() == *recipient, ErrorCode::InvalidRecipients);
+
+            let asset_id = next_asset_id(&ctx.accounts.tree_config, &ctx.accounts.merkle_tree)?;
+            mint_placeholder_leaf(
+                config,
+                ctx.accounts.mint_to_collection_accounts(recipient_info.clone()),
+                mint_index,
+                ctx.bumps.collection_config,
+            )?;
+
+            create_mint_record(
+                config.key(),
+                &ctx.accounts.authority.to_account_info(),
+                &ctx.accounts.system_program.to_account_info(),
+                record_info,
+                MintRecord {
+                    collection_config: config.key(),
+                    mint_index,
+                    asset_id,
+                    merkle_tree: ctx.accounts.merkle_tree.key(),
+                    minter: *recipient,
+                    price_paid: 0,
+                    minted_at: now,
+                    is_revealed: false,
+                    rarity_score: None,
+                },
+            )?;
+
+            emit!(MintEvent {
+                mint_index,
+                minter: *recipient,
+                merkle_tree: ctx.accounts.merkle_tree.key(),
+                is_reserved: true,
+                timestamp: now,
+            });
+        }
+
+        let config = &mut ctx.accounts.collection_config;
+        config.current_supply += quantity;
+        config.reserved_minted += quantity;
+        if fills_tree {
+            config.active_tree += 1;
+        }
+
+        msg!("Minted {} reserved NFTs (#{} - #{}), {} of {} reserved used", 
+            quantity, first_index, first_index + quantity - 1,
+            config.reserved_minted, config.reserved_supply);
 
         Ok(())
     }
 
     /// Trigger reveal for the collection
+    ///
+    /// `seed_secret` must match the commitment made at initialization. The
+    /// final seed is derived by `finalize_reveal_seed` once `seed_slot` passes.
+    /// Minting closes here, since rarity is computable from the secret onwards.
     pub fn reveal_collection(
         ctx: Context<RevealCollection>,
         revealed_base_uri: String,
+        seed_secret: [u8; 32],
     ) -> Result<()> {
         let config = &mut ctx.accounts.collection_config;
 
         require!(!config.is_revealed, ErrorCode::AlreadyRevealed);
         require!(
-            config.current_supply >= config.reveal_threshold,
+            paid_supply(config) >= config.reveal_threshold,
             ErrorCode::ThresholdNotMet
         );
+        // Every per-item `<base>/<index>.json` URI must fit the URI cap
+        require!(
+            revealed_base_uri.len() <= MAX_URI_LENGTH
+                && revealed_base_uri.trim_end_matches('/').len()
+                    + revealed_uri_suffix_len(config.max_supply)
+                    <= MAX_URI_LENGTH,
+            ErrorCode::UriTooLong
+        );
+        require!(
+            keccak::hash(&seed_secret).to_bytes() == config.seed_commitment,
+            ErrorCode::InvalidSeedSecret
+        );
 
         config.is_revealed = true;
+        config.revealed_base_uri = revealed_base_uri.clone();
+        config.seed_secret = seed_secret;
+        config.seed_slot = Clock::get()?.slot + SEED_SLOT_DELAY;
 
         emit!(RevealEvent {
             timestamp: Clock::get()?.unix_timestamp,
@@ -291,184 +637,3169 @@ pub mod analos_nft_launchpad {
         Ok(())
     }
 
-    /// Update NFT metadata after reveal
-    pub fn update_nft_metadata(
-        ctx: Context<UpdateNftMetadata>,
-        new_uri: String,
+    /// Derive the reveal seed from the revealed secret and the slot hash of `seed_slot`
+    ///
+    /// Permissionless. If `seed_slot` has already dropped out of the SlotHashes
+    /// sysvar, a new target slot is scheduled instead.
+    ///
+    /// Limitation: the authority knows the secret, so once `seed_slot` passes it
+    /// can compute the outcome and, if nobody else finalizes within the ~512-slot
+    /// SlotHashes window, withhold finalization to force a reschedule and a new
+    /// roll. Holders or a keeper should call this promptly after `seed_slot`.
+    pub fn finalize_reveal_seed(ctx: Context<FinalizeRevealSeed>) -> Result<()> {
+        let config = &mut ctx.accounts.collection_config;
+        let current_slot = Clock::get()?.slot;
+
+        require!(config.is_revealed, ErrorCode::NotRevealed);
+        require!(!config.is_seed_final, ErrorCode::SeedAlreadyFinalized);
+        require!(current_slot > config.seed_slot, ErrorCode::SeedSlotNotReached);
+
+        let slot_hashes = ctx.accounts.slot_hashes.try_borrow_data()?;
+        let Some(slot_hash) = find_slot_hash(&slot_hashes, config.seed_slot) else {
+            require!(
+                current_slot > config.seed_slot + SLOT_HASHES_MAX_ENTRIES,
+                ErrorCode::SlotHashNotFound
+            );
+            config.seed_slot = current_slot + SEED_SLOT_DELAY;
+            msg!("Seed slot expired, rescheduled to {}", config.seed_slot);
+            return Ok(());
+        };
+
+        config.global_seed = keccak::hashv(&[
+            &config.seed_secret,
+            &slot_hash,
+            config.key().as_ref(),
+        ])
+        .to_bytes();
+        config.is_seed_final = true;
+
+        emit!(RevealSeedFinalizedEvent {
+            collection_config: config.key(),
+            seed_secret: config.seed_secret,
+            seed_slot: config.seed_slot,
+            slot_hash,
+            global_seed: config.global_seed,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Reveal seed finalized at slot {}", config.seed_slot);
+
+        Ok(())
+    }
+
+    /// Reveal a single compressed NFT after the collection reveal
+    ///
+    /// Rewrites the leaf name and URI from the placeholder to
+    /// `revealed_base_uri/<index>.json`. Bubblegum hashes the leaf's current
+    /// metadata to check it against the proof, so the mint-time placeholder is
+    /// rebuilt from the config; the fields it uses are frozen after the first
+    /// mint. The leaf proof nodes are passed as remaining accounts.
+    pub fn update_nft_metadata<'info>(
+        ctx: Context<'_, '_, '_, 'info, UpdateNftMetadata<'info>>,
+        root: [u8; 32],
+        nonce: u64,
+        index: u32,
     ) -> Result<()> {
         let config = &ctx.accounts.collection_config;
+        require!(config.mint_mode == MintMode::Compressed, ErrorCode::UnsupportedMintMode);
+        require!(config.is_revealed, ErrorCode::NotRevealed);
+        require!(config.is_seed_final, ErrorCode::SeedNotFinalized);
+
+        // `index` is the leaf index within the record's tree, not the mint index
+        let mint_record = &ctx.accounts.mint_record;
+        let mint_index = mint_record.mint_index;
+        require!(mint_index < config.current_supply, ErrorCode::InvalidMintIndex);
+        require!(
+            mint_record.merkle_tree == ctx.accounts.merkle_tree.key()
+                && mint_record.asset_id == get_asset_id(&mint_record.merkle_tree, nonce),
+            ErrorCode::InvalidMintRecord
+        );
+
+        let new_uri = revealed_uri(config, mint_index);
+        let current_metadata = placeholder_metadata_args(config, mint_index);
+        let update_args = UpdateArgs {
+            name: Some(format!("{} #{}", config.collection_name, mint_index)),
+            symbol: None,
+            uri: Some(new_uri.clone()),
+            creators: None,
+            seller_fee_basis_points: None,
+            primary_sale_happened: None,
+            is_mutable: None,
+        };
+
+        // Collection config PDA signs as the tree delegate and collection authority
+        let seeds = config.signer_seeds(ctx.bumps.collection_config);
+        let signer_seeds = &[&seeds.as_seeds()[..]];
+
+        let collection_mint = ctx.accounts.collection_mint.to_account_info();
+        let collection_config = ctx.accounts.collection_config.to_account_info();
+        let proof: Vec<_> = ctx
+            .remaining_accounts
+            .iter()
+            .map(|node| (node, false, false))
+            .collect();
+
+        UpdateMetadataCpiBuilder::new(&ctx.accounts.bubblegum_program)
+            .tree_config(&ctx.accounts.tree_config)
+            .authority(&collection_config)
+            .collection_mint(Some(&collection_mint))
+            .collection_metadata(Some(&ctx.accounts.collection_metadata))
+            .collection_authority_record_pda(Some(&ctx.accounts.collection_authority_record_pda))
+            .leaf_owner(&ctx.accounts.leaf_owner)
+            .leaf_delegate(&ctx.accounts.leaf_delegate)
+            .payer(&ctx.accounts.authority)
+            .merkle_tree(&ctx.accounts.merkle_tree)
+            .log_wrapper(&ctx.accounts.log_wrapper)
+            .compression_program(&ctx.accounts.compression_program)
+            .token_metadata_program(&ctx.accounts.token_metadata_program)
+            .system_program(&ctx.accounts.system_program)
+            .root(root)
+            .nonce(nonce)
+            .index(index)
+            .current_metadata(current_metadata)
+            .update_args(update_args)
+            .add_remaining_accounts(&proof)
+            .invoke_signed(signer_seeds)?;
+
+        let rarity_score = rarity_score(&ctx.accounts.collection_config.global_seed, mint_index);
+
+        let mint_record = &mut ctx.accounts.mint_record;
+        mint_record.is_revealed = true;
+        mint_record.rarity_score = Some(rarity_score);
+
+        emit!(MetadataUpdateEvent {
+            mint_index,
+            leaf_owner: ctx.accounts.leaf_owner.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+            new_uri: new_uri.clone(),
+            rarity_score,
+        });
+
+        msg!("Revealed NFT #{}: {}", mint_index, new_uri);
+
+        Ok(())
+    }
+
+    /// Reveal a single standard NFT after the collection reveal
+    ///
+    /// Points its analos-metadata URI at `revealed_base_uri/<index>.json`.
+    pub fn reveal_standard_nft(ctx: Context<RevealStandardNft>) -> Result<()> {
+        let config = &ctx.accounts.collection_config;
+        require!(config.mint_mode == MintMode::Standard, ErrorCode::UnsupportedMintMode);
         require!(config.is_revealed, ErrorCode::NotRevealed);
+        require!(config.is_seed_final, ErrorCode::SeedNotFinalized);
+
+        let mint_index = ctx.accounts.mint_record.mint_index;
+        let new_uri = revealed_uri(config, mint_index);
+
+        // Collection config PDA signs as the metadata update authority
+        let seeds = config.signer_seeds(ctx.bumps.collection_config);
+        let signer_seeds = &[&seeds.as_seeds()[..]];
+
+        let update_accounts = UpdateStandardMetadata {
+            metadata: ctx.accounts.nft_metadata.to_account_info(),
+            update_authority: config.to_account_info(),
+        };
+        update_standard_metadata(
+            CpiContext::new_with_signer(
+                ctx.accounts.analos_metadata_program.to_account_info(),
+                update_accounts,
+                signer_seeds,
+            ),
+            Some(format!("{} #{}", config.collection_name, mint_index)),
+            None,
+            Some(new_uri.clone()),
+        )?;
 
-        // This would typically involve updating the metadata URI
-        // For compressed NFTs, this is more complex and may require
-        // additional logic or external services
+        let rarity_score = rarity_score(&config.global_seed, mint_index);
+
+        let mint_record = &mut ctx.accounts.mint_record;
+        mint_record.is_revealed = true;
+        mint_record.rarity_score = Some(rarity_score);
 
         emit!(MetadataUpdateEvent {
+            mint_index,
+            leaf_owner: mint_record.minter,
             timestamp: Clock::get()?.unix_timestamp,
-            new_uri,
+            new_uri: new_uri.clone(),
+            rarity_score,
         });
 
+        msg!("Revealed NFT #{}: {}", mint_index, new_uri);
+
         Ok(())
     }
 
     /// Withdraw collected funds
-    pub fn withdraw_funds(ctx: Context<WithdrawFunds>, amount: u64) -> Result<()> {
+    ///
+    /// Pays `authority` or, if a payout split is configured, each payout wallet
+    /// its share; remaining accounts are then the payout wallets in order. The
+    /// config account always keeps its rent-exempt minimum.
+    pub fn withdraw_funds<'info>(
+        ctx: Context<'_, '_, '_, 'info, WithdrawFunds<'info>>,
+        amount: u64,
+    ) -> Result<()> {
         let config = &ctx.accounts.collection_config;
         require!(
             ctx.accounts.authority.key() == config.authority,
             ErrorCode::Unauthorized
         );
 
-        let collection_balance = config.to_account_info().lamports();
+        let now = Clock::get()?.unix_timestamp;
         require!(
-            collection_balance >= amount,
+            amount <= withdrawable_balance(config, now)?,
             ErrorCode::InsufficientFunds
         );
 
-        **ctx.accounts.collection_config.to_account_info().lamports.borrow_mut() -= amount;
-        **ctx.accounts.authority.to_account_info().lamports.borrow_mut() += amount;
+        let payee_accounts = if config.payout_split.is_empty() {
+            vec![ctx.accounts.authority.to_account_info()]
+        } else {
+            ctx.remaining_accounts.to_vec()
+        };
+        let shares = payout_shares(config);
+        let payee_amounts = pay_out_shares(
+            &config.to_account_info(),
+            &shares,
+            &payee_accounts,
+            amount,
+        )?;
 
         emit!(WithdrawEvent {
             amount,
             authority: config.authority,
-            timestamp: Clock::get()?.unix_timestamp,
+            payees: shares.iter().map(|share| share.wallet).collect(),
+            payee_amounts,
+            timestamp: now,
         });
 
-        msg!("Withdrew {} lamports to {}", amount, config.authority);
+        msg!("Withdrew {} lamports to {} payee(s)", amount, shares.len());
 
         Ok(())
     }
 
-    /// Pause/unpause collection minting
-    pub fn pause_collection(ctx: Context<PauseCollection>, is_paused: bool) -> Result<()> {
+    /// Push the whole withdrawable balance out according to the payout split (permissionless)
+    ///
+    /// Remaining accounts: the payout wallets in split order, or `authority`
+    /// when no split is configured.
+    pub fn distribute<'info>(ctx: Context<'_, '_, '_, 'info, Distribute<'info>>) -> Result<()> {
+        let config = &ctx.accounts.collection_config;
+        let now = Clock::get()?.unix_timestamp;
+
+        let amount = withdrawable_balance(config, now)?;
+        require!(amount > 0, ErrorCode::InsufficientFunds);
+
+        let shares = payout_shares(config);
+        let payee_amounts = pay_out_shares(
+            &config.to_account_info(),
+            &shares,
+            ctx.remaining_accounts,
+            amount,
+        )?;
+
+        emit!(PayoutDistributedEvent {
+            collection_config: config.key(),
+            amount,
+            payees: shares.iter().map(|share| share.wallet).collect(),
+            payee_amounts,
+            cranker: ctx.accounts.cranker.key(),
+            timestamp: now,
+        });
+
+        msg!("Distributed {} lamports to {} payee(s)", amount, shares.len());
+
+        Ok(())
+    }
+
+    /// Split withdrawals among several wallets (basis points summing to 10000), or clear the split
+    pub fn set_payout_split(ctx: Context<SetPayoutSplit>, payout_split: Vec<PayoutShare>) -> Result<()> {
+        validate_payout_split(&payout_split)?;
+
         let config = &mut ctx.accounts.collection_config;
+        config.payout_split = payout_split.clone();
+
+        emit!(PayoutSplitUpdatedEvent {
+            collection_config: config.key(),
+            payout_split,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Payout split updated: {} wallet(s)", config.payout_split.len());
+
+        Ok(())
+    }
+
+    /// Claim the Dutch auction rebate down to the clearing price once the auction ends
+    ///
+    /// The treasury only receives the net creator share of each payment, so the
+    /// rebate is that share of the amount paid above the clearing price; fees
+    /// and referral rewards on the overpayment aren't refunded.
+    pub fn claim_auction_rebate(ctx: Context<ClaimAuctionRebate>) -> Result<()> {
+        let config = &ctx.accounts.collection_config;
+        let counter = &mut ctx.accounts.wallet_mint_counter;
+
         require!(
-            ctx.accounts.authority.key() == config.authority,
-            ErrorCode::Unauthorized
+            matches!(
+                config.pricing_mode,
+                PricingMode::DutchAuction { rebates_enabled: true, .. }
+            ),
+            ErrorCode::RebatesDisabled
         );
+        let clearing_price = auction_clearing_price(config, Clock::get()?.unix_timestamp)
+            .ok_or(ErrorCode::AuctionNotEnded)?;
+        require!(!counter.rebate_claimed, ErrorCode::RebateAlreadyClaimed);
 
-        config.is_paused = is_paused;
+        let units = auction_clearing_units(
+            counter.auction_paid,
+            counter.auction_net_paid,
+            counter.auction_minted,
+        );
+        let rebate = auction_rebate(counter.auction_net_paid, units, clearing_price);
+        require!(rebate > 0, ErrorCode::NoRebateDue);
 
-        emit!(PauseEvent {
-            is_paused,
+        counter.rebate_claimed = true;
+
+        **ctx.accounts.collection_config.to_account_info().try_borrow_mut_lamports()? -= rebate;
+        **ctx.accounts.minter.to_account_info().try_borrow_mut_lamports()? += rebate;
+
+        let config = &mut ctx.accounts.collection_config;
+        config.auction_rebates_paid += rebate;
+
+        emit!(AuctionRebateClaimedEvent {
+            collection_config: config.key(),
+            minter: ctx.accounts.minter.key(),
+            clearing_price,
+            rebate,
             timestamp: Clock::get()?.unix_timestamp,
         });
 
-        msg!("Collection {} paused: {}", config.collection_name, is_paused);
+        msg!("Auction rebate of {} lamports claimed by {} (clearing price: {})", 
+            rebate, ctx.accounts.minter.key(), clearing_price);
 
         Ok(())
     }
 
-    /// Update collection configuration
-    pub fn update_config(
-        ctx: Context<UpdateConfig>,
-        new_price: Option<u64>,
-        new_reveal_threshold: Option<u64>,
+    /// Set or clear the reveal deadline (only before the first mint)
+    ///
+    /// While set, whole mint payments (fees and referral rewards included) stay
+    /// in escrow until the reveal threshold is met; fees and referral rewards are
+    /// then paid out by `release_escrowed_fees` / `claim_referral_rewards`. If the
+    /// deadline passes first, minting stops and minters can claim full refunds.
+    pub fn set_reveal_deadline(
+        ctx: Context<SetRevealDeadline>,
+        reveal_deadline: Option<i64>,
     ) -> Result<()> {
         let config = &mut ctx.accounts.collection_config;
-        require!(
-            ctx.accounts.authority.key() == config.authority,
-            ErrorCode::Unauthorized
-        );
-
-        if let Some(price) = new_price {
-            config.price_lamports = price;
-        }
+        require!(!config.is_locked, ErrorCode::ConfigLocked);
 
-        if let Some(threshold) = new_reveal_threshold {
+        require!(config.current_supply == 0, ErrorCode::MintingStarted);
+        if let Some(deadline) = reveal_deadline {
+            require!(
+                deadline > Clock::get()?.unix_timestamp,
+                ErrorCode::InvalidRevealDeadline
+            );
+            require!(config.payment_mint.is_none(), ErrorCode::EscrowRequiresLamports);
             require!(
-                threshold <= config.max_supply && threshold > 0,
-                ErrorCode::InvalidThreshold
+                !has_auction_rebates(config.pricing_mode),
+                ErrorCode::RebatesWithEscrow
             );
-            config.reveal_threshold = threshold;
         }
 
-        emit!(ConfigUpdateEvent {
-            new_price,
-            new_reveal_threshold,
+        config.reveal_deadline = reveal_deadline;
+
+        emit!(RevealDeadlineUpdatedEvent {
+            collection_config: config.key(),
+            reveal_deadline,
             timestamp: Clock::get()?.unix_timestamp,
         });
 
-        msg!("Config updated: price={:?}, threshold={:?}", new_price, new_reveal_threshold);
+        msg!("Reveal deadline updated: {:?}", reveal_deadline);
 
         Ok(())
     }
-}
 
-// ========== ACCOUNTS ==========
+    /// Refund a minter's escrowed payment after the reveal deadline was missed
+    pub fn claim_refund(ctx: Context<ClaimRefund>) -> Result<()> {
+        let config = &ctx.accounts.collection_config;
+        let counter = &mut ctx.accounts.wallet_mint_counter;
+
+        require!(
+            refunds_open(config, Clock::get()?.unix_timestamp),
+            ErrorCode::RefundsNotAvailable
+        );
+        require!(!counter.refunded, ErrorCode::RefundAlreadyClaimed);
+
+        let amount = counter.escrowed_lamports;
+        require!(amount > 0, ErrorCode::NoRefundDue);
+
+        counter.refunded = true;
+
+        **ctx.accounts.collection_config.to_account_info().try_borrow_mut_lamports()? -= amount;
+        **ctx.accounts.minter.to_account_info().try_borrow_mut_lamports()? += amount;
+
+        let config = &mut ctx.accounts.collection_config;
+        config.refunds_paid += amount;
+
+        emit!(RefundClaimedEvent {
+            collection_config: config.key(),
+            minter: ctx.accounts.minter.key(),
+            amount,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Refunded {} lamports to {}", amount, ctx.accounts.minter.key());
+
+        Ok(())
+    }
+
+    /// Pay platform fees escrowed under a reveal deadline once the threshold is met (permissionless)
+    ///
+    /// Fees are split across the collection's current fee recipients by basis
+    /// points. Remaining accounts: the fee recipient wallets in order.
+    pub fn release_escrowed_fees<'info>(
+        ctx: Context<'_, '_, '_, 'info, ReleaseEscrowedFees<'info>>,
+    ) -> Result<()> {
+        let config = &ctx.accounts.collection_config;
+        require!(
+            paid_supply(config) >= config.reveal_threshold,
+            ErrorCode::ProceedsLocked
+        );
+
+        let amount = config.pending_fee_lamports;
+        require!(amount > 0, ErrorCode::NothingToRelease);
+
+        let fee_recipients = config
+            .fee_override
+            .clone()
+            .unwrap_or_else(|| ctx.accounts.platform_config.fee_recipients.clone());
+        let shares = fee_shares(&fee_recipients);
+        require!(!shares.is_empty(), ErrorCode::MissingFeeRecipient);
+        let fee_amounts = pay_out_shares(
+            &config.to_account_info(),
+            &shares,
+            ctx.remaining_accounts,
+            amount,
+        )?;
+
+        let config = &mut ctx.accounts.collection_config;
+        config.pending_fee_lamports = 0;
+
+        emit!(EscrowedFeesReleasedEvent {
+            collection_config: config.key(),
+            amount,
+            fee_recipients: shares.iter().map(|share| share.wallet).collect(),
+            fee_amounts,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Released {} lamports of escrowed fees to {} recipient(s)", amount, shares.len());
+
+        Ok(())
+    }
+
+    /// Pay a referrer's rewards escrowed under a reveal deadline once the threshold is met (permissionless)
+    pub fn claim_referral_rewards(ctx: Context<ClaimReferralRewards>) -> Result<()> {
+        let config = &ctx.accounts.collection_config;
+        require!(
+            paid_supply(config) >= config.reveal_threshold,
+            ErrorCode::ProceedsLocked
+        );
+
+        let stats = &mut ctx.accounts.referral_stats;
+        let amount = stats.pending_lamports;
+        require!(amount > 0, ErrorCode::NothingToRelease);
+
+        stats.pending_lamports = 0;
+        stats.lamports_earned += amount;
+
+        **ctx.accounts.collection_config.to_account_info().try_borrow_mut_lamports()? -= amount;
+        **ctx.accounts.referrer.to_account_info().try_borrow_mut_lamports()? += amount;
+
+        let config = &mut ctx.accounts.collection_config;
+        config.pending_referral_lamports -= amount;
+
+        emit!(ReferralRewardClaimedEvent {
+            collection_config: config.key(),
+            referrer: ctx.accounts.referrer.key(),
+            amount,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Paid {} lamports of escrowed referral rewards to {}", amount, ctx.accounts.referrer.key());
+
+        Ok(())
+    }
+
+    /// Propose a new collection authority (e.g. a multisig), or cancel with `None`
+    ///
+    /// The transfer completes once the proposed wallet calls `accept_authority`.
+    /// The collection stays listed in the original authority's registry.
+    pub fn propose_authority(
+        ctx: Context<ProposeAuthority>,
+        new_authority: Option<Pubkey>,
+    ) -> Result<()> {
+        let config = &mut ctx.accounts.collection_config;
+
+        config.pending_authority = new_authority;
+
+        emit!(AuthorityProposedEvent {
+            collection_config: config.key(),
+            authority: config.authority,
+            pending_authority: new_authority,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Authority transfer proposed: {:?}", new_authority);
+
+        Ok(())
+    }
+
+    /// Accept a proposed collection authority transfer
+    pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+        let config = &mut ctx.accounts.collection_config;
+
+        require!(
+            config.pending_authority == Some(ctx.accounts.new_authority.key()),
+            ErrorCode::Unauthorized
+        );
+
+        let previous_authority = config.authority;
+        config.authority = ctx.accounts.new_authority.key();
+        config.pending_authority = None;
+
+        emit!(AuthorityTransferredEvent {
+            collection_config: config.key(),
+            previous_authority,
+            new_authority: config.authority,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Authority transferred from {} to {}", previous_authority, config.authority);
+
+        Ok(())
+    }
+
+    /// Close a finished collection, returning the config rent to the authority
+    ///
+    /// Only allowed once nothing is owed: no refunds or auction rebates pending
+    /// and everything above the rent-exempt minimum withdrawn. The collection is
+    /// removed from the registry; Bubblegum tree accounts aren't owned by this
+    /// program and stay open.
+    ///
+    /// The config PDA is the tree delegate and metadata update authority, so a
+    /// minted collection must be revealed with a final seed, and every item
+    /// revealed via `update_nft_metadata` / `reveal_standard_nft`, before closing;
+    /// items still on the placeholder can't be revealed afterwards.
+    pub fn close_collection(ctx: Context<CloseCollection>) -> Result<()> {
+        let config = &ctx.accounts.collection_config;
+        let now = Clock::get()?.unix_timestamp;
+
+        require!(
+            config.current_supply == 0 || (config.is_revealed && config.is_seed_final),
+            ErrorCode::RevealPending
+        );
+
+        // Escrowed proceeds stay refundable until the threshold is met or every refund is claimed
+        let refunds_pending = config.reveal_deadline.is_some()
+            && paid_supply(config) < config.reveal_threshold
+            && config.escrow_total > config.refunds_paid;
+        require!(!refunds_pending, ErrorCode::RefundsPending);
+        require!(
+            auction_rebate_reserve(config, now) == 0,
+            ErrorCode::RebatesPending
+        );
+
+        let config_info = config.to_account_info();
+        let rent_exempt_minimum = Rent::get()?.minimum_balance(config_info.data_len());
+        require!(
+            config_info.lamports() <= rent_exempt_minimum,
+            ErrorCode::BalanceNotWithdrawn
+        );
+
+        let collection_key = config.key();
+        ctx.accounts
+            .collection_registry
+            .collections
+            .retain(|collection| *collection != collection_key);
+
+        emit!(CollectionClosedEvent {
+            collection_config: collection_key,
+            authority: config.authority,
+            collection_id: config.collection_id,
+            current_supply: config.current_supply,
+            max_supply: config.max_supply,
+            reserved_minted: config.reserved_minted,
+            is_revealed: config.is_revealed,
+            escrow_total: config.escrow_total,
+            refunds_paid: config.refunds_paid,
+            lamports_reclaimed: config_info.lamports(),
+            timestamp: now,
+        });
+
+        msg!("Collection {} closed with {} of {} minted",
+            config.collection_name, config.current_supply, config.max_supply);
+
+        Ok(())
+    }
+
+    /// Pause/unpause collection minting
+    pub fn pause_collection(ctx: Context<PauseCollection>, is_paused: bool) -> Result<()> {
+        let config = &mut ctx.accounts.collection_config;
+        require!(
+            ctx.accounts.authority.key() == config.authority,
+            ErrorCode::Unauthorized
+        );
+
+        config.is_paused = is_paused;
+
+        emit!(PauseEvent {
+            is_paused,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Collection {} paused: {}", config.collection_name, is_paused);
+
+        Ok(())
+    }
+
+    /// Replace the ordered list of mint phases (empty = single public phase)
+    pub fn set_mint_phases(ctx: Context<SetMintPhases>, phases: Vec<MintPhase>) -> Result<()> {
+        let config = &mut ctx.accounts.collection_config;
+        require!(!config.is_locked, ErrorCode::ConfigLocked);
+
+        require!(phases.len() <= MAX_MINT_PHASES, ErrorCode::TooManyPhases);
+        for (i, phase) in phases.iter().enumerate() {
+            require!(phase.start_time < phase.end_time, ErrorCode::InvalidPhase);
+            if i > 0 {
+                require!(
+                    phase.start_time >= phases[i - 1].end_time,
+                    ErrorCode::InvalidPhase
+                );
+            }
+        }
+
+        config.mint_phases = phases;
+
+        emit!(MintPhasesUpdatedEvent {
+            collection_config: config.key(),
+            phase_count: config.mint_phases.len() as u8,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Mint phases updated: {} phases", config.mint_phases.len());
+
+        Ok(())
+    }
+
+    /// Price the collection in an SPL token (e.g. $LOL), or clear it to use lamports
+    pub fn set_payment_mint(
+        ctx: Context<SetPaymentMint>,
+        payment_mint: Option<Pubkey>,
+        payment_price: u64,
+    ) -> Result<()> {
+        let config = &mut ctx.accounts.collection_config;
+        require!(!config.is_locked, ErrorCode::ConfigLocked);
+
+        // Refund escrow only holds lamports
+        require!(
+            payment_mint.is_none() || config.reveal_deadline.is_none(),
+            ErrorCode::EscrowRequiresLamports
+        );
+
+        config.payment_mint = payment_mint;
+        config.payment_price = if payment_mint.is_some() { payment_price } else { 0 };
+
+        emit!(PaymentMintUpdatedEvent {
+            collection_config: config.key(),
+            payment_mint: config.payment_mint,
+            payment_price: config.payment_price,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Payment mint updated: {:?} at {} base units", 
+            config.payment_mint, config.payment_price);
+
+        Ok(())
+    }
+
+    /// Gate minting on holding a token balance or a verified-collection NFT, or clear the gate
+    pub fn set_token_gate(ctx: Context<SetTokenGate>, token_gate: Option<TokenGate>) -> Result<()> {
+        let config = &mut ctx.accounts.collection_config;
+        require!(!config.is_locked, ErrorCode::ConfigLocked);
+
+        if let Some(TokenGate {
+            requirement: GateRequirement::TokenBalance { min_amount, .. },
+            ..
+        }) = token_gate
+        {
+            require!(min_amount > 0, ErrorCode::InvalidTokenGate);
+        }
+
+        config.token_gate = token_gate;
+
+        emit!(TokenGateUpdatedEvent {
+            collection_config: config.key(),
+            token_gate,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Token gate updated: {:?}", token_gate);
+
+        Ok(())
+    }
+
+    /// Link an analos-token-launch config so every mint funds its escrow, or unlink it
+    pub fn set_token_launch(ctx: Context<SetTokenLaunch>) -> Result<()> {
+        let token_launch_config = ctx.accounts.token_launch_config.as_ref().map(|c| c.key());
+        let config = &mut ctx.accounts.collection_config;
+        require!(!config.is_locked, ErrorCode::ConfigLocked);
+
+        config.token_launch_config = token_launch_config;
+
+        emit!(TokenLaunchLinkedEvent {
+            collection_config: config.key(),
+            token_launch_config,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Token launch linked: {:?}", token_launch_config);
+
+        Ok(())
+    }
+
+    /// Require a co-signature from `mint_guard_signer` on every mint, or clear it
+    pub fn set_mint_guard(ctx: Context<SetMintGuard>, mint_guard_signer: Option<Pubkey>) -> Result<()> {
+        let config = &mut ctx.accounts.collection_config;
+        require!(!config.is_locked, ErrorCode::ConfigLocked);
+
+        config.mint_guard_signer = mint_guard_signer;
+
+        emit!(MintGuardUpdatedEvent {
+            collection_config: config.key(),
+            mint_guard_signer,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Mint guard updated: {:?}", mint_guard_signer);
+
+        Ok(())
+    }
+
+    /// Set the share of the creator payment paid to referrers
+    pub fn set_referral_bps(ctx: Context<SetReferralBps>, referral_bps: u16) -> Result<()> {
+        require!(
+            referral_bps <= MAX_REFERRAL_BASIS_POINTS,
+            ErrorCode::InvalidReferralBps
+        );
+
+        let config = &mut ctx.accounts.collection_config;
+        require!(!config.is_locked, ErrorCode::ConfigLocked);
+        config.referral_bps = referral_bps;
+
+        emit!(ReferralBpsUpdatedEvent {
+            collection_config: config.key(),
+            referral_bps,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Referral share set to {} bps", referral_bps);
+
+        Ok(())
+    }
+
+    /// Set how the lamport mint price is computed
+    pub fn set_pricing_mode(ctx: Context<SetPricingMode>, pricing_mode: PricingMode) -> Result<()> {
+        let config = &mut ctx.accounts.collection_config;
+        require!(!config.is_locked, ErrorCode::ConfigLocked);
+
+        match pricing_mode {
+            PricingMode::UsdPegged { price_usd } => {
+                require!(price_usd > 0, ErrorCode::InvalidPrice);
+            }
+            PricingMode::DutchAuction {
+                start_price,
+                floor_price,
+                decay_interval,
+                ..
+            } => {
+                require!(
+                    start_price >= floor_price && floor_price > 0 && decay_interval > 0,
+                    ErrorCode::InvalidAuction
+                );
+            }
+            PricingMode::ExponentialCurve { growth_basis_points, .. } => {
+                require!(growth_basis_points > 0, ErrorCode::InvalidCurve);
+            }
+            PricingMode::Fixed | PricingMode::LinearCurve { .. } => {}
+        }
+
+        // Auction accounting can't change once minting has started
+        let is_auction = |mode: PricingMode| matches!(mode, PricingMode::DutchAuction { .. });
+        if is_auction(config.pricing_mode) || is_auction(pricing_mode) {
+            require!(config.current_supply == 0, ErrorCode::MintingStarted);
+        }
+
+        // Refunds and rebates would both draw on the same escrowed proceeds
+        require!(
+            !(has_auction_rebates(pricing_mode) && config.reveal_deadline.is_some()),
+            ErrorCode::RebatesWithEscrow
+        );
+
+        config.pricing_mode = pricing_mode;
+
+        emit!(PricingModeUpdatedEvent {
+            collection_config: config.key(),
+            pricing_mode,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Pricing mode updated: {:?}", pricing_mode);
+
+        Ok(())
+    }
+
+    /// Update royalties and creator splits (only before the first mint)
+    ///
+    /// Same 1-4 creator limit as `initialize_collection`; the fifth Bubblegum
+    /// creator slot belongs to the collection config PDA.
+    pub fn update_royalties(
+        ctx: Context<UpdateRoyalties>,
+        royalty_basis_points: u16,
+        creators: Vec<CreatorShare>,
+    ) -> Result<()> {
+        let config = &mut ctx.accounts.collection_config;
+
+        require!(!config.is_locked, ErrorCode::ConfigLocked);
+        require!(config.current_supply == 0, ErrorCode::MintingStarted);
+        validate_creators(royalty_basis_points, &creators, &config.key())?;
+
+        config.royalty_basis_points = royalty_basis_points;
+        config.creators = creators;
+
+        emit!(RoyaltiesUpdatedEvent {
+            collection_config: config.key(),
+            royalty_basis_points,
+            creators: config.creators.clone(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Royalties updated: {} bps across {} creators", 
+            royalty_basis_points, config.creators.len());
+
+        Ok(())
+    }
+
+    /// Update collection configuration (until `lock_config`)
+    ///
+    /// `new_max_supply` can't drop below the minted supply plus the unminted
+    /// reserve, nor below the reveal threshold. Name, symbol and placeholder URI
+    /// are fixed once minting starts: revealing a compressed leaf rebuilds its
+    /// placeholder metadata from them. With a reveal deadline set, the threshold
+    /// and max supply are fixed once minting starts since they gate the escrow.
+    /// Max supply is also fixed once a rebate auction has mints, since selling
+    /// out sets the clearing price.
+    pub fn update_config(
+        ctx: Context<UpdateConfig>,
+        new_price: Option<u64>,
+        new_reveal_threshold: Option<u64>,
+        new_name: Option<String>,
+        new_symbol: Option<String>,
+        new_placeholder_uri: Option<String>,
+        new_max_supply: Option<u64>,
+    ) -> Result<()> {
+        let config = &mut ctx.accounts.collection_config;
+        require!(
+            ctx.accounts.authority.key() == config.authority,
+            ErrorCode::Unauthorized
+        );
+        require!(!config.is_locked, ErrorCode::ConfigLocked);
+
+        // Lowering the threshold would unlock escrowed proceeds and close refunds
+        if config.reveal_deadline.is_some() && config.current_supply > 0 {
+            require!(
+                new_reveal_threshold.is_none() && new_max_supply.is_none(),
+                ErrorCode::EscrowTermsLocked
+            );
+        }
+        if has_auction_rebates(config.pricing_mode) && config.current_supply > 0 {
+            require!(new_max_supply.is_none(), ErrorCode::MintingStarted);
+        }
+        if config.current_supply > 0 {
+            require!(
+                new_name.is_none() && new_symbol.is_none() && new_placeholder_uri.is_none(),
+                ErrorCode::MintingStarted
+            );
+        }
+
+        if let Some(price) = new_price {
+            config.price_lamports = price;
+        }
+
+        if let Some(max_supply) = new_max_supply {
+            let committed_supply =
+                config.current_supply + (config.reserved_supply - config.reserved_minted);
+            require!(
+                max_supply > 0 && max_supply >= committed_supply,
+                ErrorCode::InvalidMaxSupply
+            );
+            config.max_supply = max_supply;
+        }
+
+        let threshold = new_reveal_threshold.unwrap_or(config.reveal_threshold);
+        require!(
+            threshold <= config.max_supply - config.reserved_supply && threshold > 0,
+            ErrorCode::InvalidThreshold
+        );
+        config.reveal_threshold = threshold;
+
+        if let Some(name) = &new_name {
+            config.collection_name = name.clone();
+        }
+        // A longer name or a larger supply must still leave room for the item suffix
+        require!(
+            item_name_fits(&config.collection_name, config.max_supply),
+            ErrorCode::NameTooLong
+        );
+
+        if let Some(symbol) = &new_symbol {
+            require!(symbol.len() <= MAX_SYMBOL_LENGTH, ErrorCode::SymbolTooLong);
+            config.collection_symbol = symbol.clone();
+        }
+
+        if let Some(uri) = &new_placeholder_uri {
+            require!(uri.len() <= MAX_URI_LENGTH, ErrorCode::UriTooLong);
+            config.placeholder_uri = uri.clone();
+        }
+
+        emit!(ConfigUpdateEvent {
+            new_price,
+            new_reveal_threshold,
+            new_name,
+            new_symbol,
+            new_placeholder_uri,
+            new_max_supply,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Config updated: price={:?}, threshold={:?}, max_supply={:?}",
+            new_price, new_reveal_threshold, new_max_supply);
+
+        Ok(())
+    }
+
+    /// Permanently freeze the collection's price and terms
+    ///
+    /// Blocks `update_config`, `update_royalties`, `set_pricing_mode`,
+    /// `set_mint_phases`, `set_payment_mint`, `set_reveal_deadline`,
+    /// `set_token_gate`, `set_token_launch`, `set_mint_guard` and `set_referral_bps`.
+    pub fn lock_config(ctx: Context<LockConfig>) -> Result<()> {
+        let config = &mut ctx.accounts.collection_config;
+        require!(!config.is_locked, ErrorCode::ConfigLocked);
+
+        config.is_locked = true;
+
+        emit!(ConfigLockedEvent {
+            collection_config: config.key(),
+            collection_name: config.collection_name.clone(),
+            collection_symbol: config.collection_symbol.clone(),
+            placeholder_uri: config.placeholder_uri.clone(),
+            max_supply: config.max_supply,
+            reserved_supply: config.reserved_supply,
+            price_lamports: config.price_lamports,
+            pricing_mode: config.pricing_mode,
+            payment_mint: config.payment_mint,
+            payment_price: config.payment_price,
+            reveal_threshold: config.reveal_threshold,
+            reveal_deadline: config.reveal_deadline,
+            royalty_basis_points: config.royalty_basis_points,
+            referral_bps: config.referral_bps,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Config locked: {} (max supply {})", config.collection_name, config.max_supply);
+
+        Ok(())
+    }
+}
+
+// ========== HELPER FUNCTIONS ==========
+
+/// Build the Bubblegum metadata for a leaf of this collection
+///
+/// The collection config PDA is listed first as a verified zero-share creator
+/// (it signs every mint), followed by the royalty-earning creators.
+fn build_metadata_args(
+    config: &CollectionConfig,
+    config_key: Pubkey,
+    name: String,
+    uri: String,
+) -> MetadataArgs {
+    MetadataArgs {
+        name,
+        symbol: config.collection_symbol.clone(),
+        uri,
+        seller_fee_basis_points: config.royalty_basis_points,
+        primary_sale_happened: false,
+        is_mutable: true, // Must stay mutable for the per-NFT reveal
+        creators: std::iter::once(Creator {
+            address: config_key,
+            verified: true,
+            share: 0,
+        })
+        .chain(config.creators.iter().map(|c| Creator {
+            address: c.address,
+            verified: false,
+            share: c.share,
+        }))
+        .collect(),
+        edition_nonce: None,
+        uses: None,
+        collection: Some(Collection {
+            verified: true,
+            key: config.collection_mint,
+        }),
+        token_program_version: TokenProgramVersion::Original,
+        token_standard: Some(TokenStandard::NonFungible),
+    }
+}
+
+/// Validate, charge and mint `quantity` placeholders to the payer.
+/// Returns the first mint index and the total payment charged.
+fn process_mint<'info>(
+    accounts: &mut MintPlaceholder<'info>,
+    remaining_accounts: &[AccountInfo<'info>],
+    allowlist_proof: &[[u8; 32]],
+    quantity: u64,
+    config_bump: u8,
+) -> Result<(u64, u64)> {
+    let config = &accounts.collection_config;
+
+    // Validations (unminted reserved supply isn't available to the public)
+    require!(!config.is_paused, ErrorCode::CollectionPaused);
+    // Rarity becomes predictable once the secret is revealed
+    require!(!config.is_revealed, ErrorCode::AlreadyRevealed);
+    require!(
+        config.current_supply + quantity + (config.reserved_supply - config.reserved_minted)
+            <= config.max_supply,
+        ErrorCode::SoldOut
+    );
+
+    let first_index = config.current_supply;
+    let now = Clock::get()?.unix_timestamp;
+    require!(!refunds_open(config, now), ErrorCode::RevealDeadlinePassed);
+
+    if let Some(guard_signer) = config.mint_guard_signer {
+        check_mint_guard(
+            &guard_signer,
+            &required_account(&accounts.instructions_sysvar)?,
+            &config.key(),
+            &accounts.payer.key(),
+            now,
+        )?;
+    }
+
+    if let Some(token_gate) = config.token_gate {
+        let fee_count = config
+            .fee_override
+            .as_ref()
+            .unwrap_or(&accounts.platform_config.fee_recipients)
+            .len();
+        check_token_gate(
+            accounts,
+            &token_gate,
+            remaining_accounts.get(fee_count..).unwrap_or(&[]),
+            quantity,
+        )?;
+    }
+
+    // Standard NFTs each need their own mint, token and metadata accounts
+    let fills_tree = match config.mint_mode {
+        MintMode::Compressed => check_active_tree(
+            config,
+            &required_account(&accounts.merkle_tree)?,
+            &required_account(&accounts.tree_config)?,
+            quantity,
+        )?,
+        MintMode::Standard => {
+            require!(quantity == 1, ErrorCode::InvalidQuantity);
+            false
+        }
+    };
+
+    let counter = &mut accounts.wallet_mint_counter;
+    counter.collection_config = config.key();
+    counter.wallet = accounts.payer.key();
+
+    // Resolve price from the active mint phase, if any
+    let mut price_lamports = config.price_lamports;
+    if !config.mint_phases.is_empty() {
+        let phase_index = config
+            .mint_phases
+            .iter()
+            .position(|phase| now >= phase.start_time && now < phase.end_time)
+            .ok_or(ErrorCode::NoActivePhase)?;
+        let phase = &config.mint_phases[phase_index];
+
+        if let Some(root) = phase.allowlist_root {
+            let leaf = keccak::hash(accounts.payer.key().as_ref()).to_bytes();
+            require!(
+                verify_allowlist_proof(allowlist_proof, root, leaf),
+                ErrorCode::NotOnAllowlist
+            );
+        }
+
+        if phase.max_per_wallet > 0 {
+            require!(
+                counter.phase_mints[phase_index] + quantity <= phase.max_per_wallet,
+                ErrorCode::WalletLimitReached
+            );
+        }
+
+        counter.phase_mints[phase_index] += quantity;
+        price_lamports = phase.price_lamports;
+    }
+    counter.total_minted += quantity;
+
+    // Price each index separately so bonding curves charge along the curve
+    let mut unit_prices = Vec::with_capacity(quantity as usize);
+    let mut total_payment: u64 = 0;
+    for mint_index in first_index..first_index + quantity {
+        let unit_price = match config.payment_mint {
+            Some(_) => config.payment_price,
+            None => resolve_lamport_price(
+                config.pricing_mode,
+                price_lamports,
+                accounts.price_oracle.as_deref(),
+                mint_index,
+                now,
+            )?,
+        };
+        total_payment = total_payment
+            .checked_add(unit_price)
+            .ok_or(ErrorCode::MathOverflow)?;
+        unit_prices.push(unit_price);
+    }
+
+    // Calculate fee distribution (one transfer per recipient for the whole batch)
+    let fee_recipients = config
+        .fee_override
+        .clone()
+        .unwrap_or_else(|| accounts.platform_config.fee_recipients.clone());
+    let (fee_amounts, creator_payment) = split_fees(total_payment, &fee_recipients);
+
+    // Referral reward is carved out of the creator payment
+    let referrer = accounts.referrer.as_ref().map(|referrer| referrer.key());
+    let referral_payment = match referrer {
+        Some(referrer) => {
+            require!(referrer != accounts.payer.key(), ErrorCode::InvalidReferrer);
+            (creator_payment as u128 * config.referral_bps as u128 / 10000) as u64
+        }
+        None => 0,
+    };
+    let creator_payment = creator_payment - referral_payment;
+
+    // Track Dutch auction payments for rebates down to the clearing price. Only
+    // the net creator payment reaches the treasury, so rebates are paid from it.
+    let is_rebate_auction = config.payment_mint.is_none()
+        && matches!(
+            config.pricing_mode,
+            PricingMode::DutchAuction { rebates_enabled: true, .. }
+        );
+    let (old_units, new_units) = if is_rebate_auction {
+        let old_units = auction_clearing_units(
+            counter.auction_paid,
+            counter.auction_net_paid,
+            counter.auction_minted,
+        );
+        counter.auction_paid += total_payment;
+        counter.auction_net_paid += creator_payment;
+        counter.auction_minted += quantity;
+        let new_units = auction_clearing_units(
+            counter.auction_paid,
+            counter.auction_net_paid,
+            counter.auction_minted,
+        );
+        (old_units, new_units)
+    } else {
+        (0, 0)
+    };
+
+    // The whole payment is escrowed (and refundable) while a reveal deadline is
+    // pending; fees and referral rewards are held until the threshold is met
+    let is_escrowed =
+        config.reveal_deadline.is_some() && paid_supply(config) < config.reveal_threshold;
+    if is_escrowed {
+        counter.escrowed_lamports += total_payment;
+    }
+
+    collect_mint_payment(
+        accounts,
+        remaining_accounts,
+        &fee_recipients,
+        &fee_amounts,
+        creator_payment,
+        referral_payment,
+        is_escrowed,
+    )?;
+
+    if let Some(referrer) = referrer {
+        record_referral(accounts, referrer, referral_payment, quantity, is_escrowed, now)?;
+    }
+
+    // One MintRecord PDA per index, passed as the last `quantity` remaining accounts
+    let record_accounts = remaining_accounts
+        .len()
+        .checked_sub(quantity as usize)
+        .map(|start| &remaining_accounts[start..])
+        .ok_or(ErrorCode::MissingMintRecord)?;
+
+    let merkle_tree = accounts
+        .merkle_tree
+        .as_ref()
+        .map_or(Pubkey::default(), |tree| tree.key());
+
+    for (mint_index, (record_info, price_paid)) in
+        (first_index..).zip(record_accounts.iter().zip(unit_prices.iter()))
+    {
+        let asset_id = match config.mint_mode {
+            MintMode::Compressed => {
+                let asset_id = next_asset_id(
+                    &required_account(&accounts.tree_config)?,
+                    &required_account(&accounts.merkle_tree)?,
+                )?;
+                mint_placeholder_leaf(
+                    config,
+                    accounts.mint_to_collection_accounts()?,
+                    mint_index,
+                    config_bump,
+                )?;
+                asset_id
+            }
+            MintMode::Standard => mint_standard_nft(accounts, mint_index, config_bump)?,
+        };
+
+        create_mint_record(
+            config.key(),
+            &accounts.payer.to_account_info(),
+            &accounts.system_program.to_account_info(),
+            record_info,
+            MintRecord {
+                collection_config: config.key(),
+                mint_index,
+                asset_id,
+                merkle_tree,
+                minter: accounts.payer.key(),
+                price_paid: *price_paid,
+                minted_at: now,
+                is_revealed: false,
+                rarity_score: None,
+            },
+        )?;
+
+        // Fund the linked token launch escrow atomically with the mint
+        if config.token_launch_config.is_some() {
+            mint_launch_tokens(accounts, asset_id, config_bump)?;
+        }
+
+        emit!(MintEvent {
+            mint_index,
+            minter: accounts.payer.key(),
+            merkle_tree,
+            is_reserved: false,
+            timestamp: now,
+        });
+    }
+
+    let config = &mut accounts.collection_config;
+    config.current_supply += quantity;
+    if fills_tree {
+        config.active_tree += 1;
+    }
+    config.last_mint_price = unit_prices.last().copied().unwrap_or_default();
+    if is_escrowed {
+        config.escrow_total += total_payment;
+        config.pending_fee_lamports += fee_amounts.iter().sum::<u64>();
+        config.pending_referral_lamports += referral_payment;
+    }
+    if is_rebate_auction {
+        config.auction_net_paid += creator_payment;
+        config.auction_clearing_units = config.auction_clearing_units + new_units - old_units;
+    }
+
+    emit!(FeeCollectionEvent {
+        mint_index: first_index,
+        quantity,
+        payment_mint: config.payment_mint,
+        total_payment,
+        effective_price: total_payment / quantity,
+        creator_payment,
+        fee_recipients: fee_recipients.iter().map(|r| r.wallet).collect(),
+        fee_amounts,
+        total_fees: total_payment - creator_payment - referral_payment,
+        referrer,
+        referral_payment,
+        timestamp: now,
+    });
+
+    Ok((first_index, total_payment))
+}
+
+/// Placeholder metadata of compressed leaf `mint_index`, as minted
+fn placeholder_metadata_args(config: &Account<CollectionConfig>, mint_index: u64) -> MetadataArgs {
+    build_metadata_args(
+        config,
+        config.key(),
+        format!("{} Mystery #{}", config.collection_name, mint_index),
+        config.placeholder_uri.clone(),
+    )
+}
+
+/// Accounts for Bubblegum's `mint_to_collection_v1`
+struct MintLeafAccounts<'info> {
+    bubblegum_program: AccountInfo<'info>,
+    tree_config: AccountInfo<'info>,
+    leaf_owner: AccountInfo<'info>,
+    merkle_tree: AccountInfo<'info>,
+    payer: AccountInfo<'info>,
+    collection_config: AccountInfo<'info>,
+    collection_authority_record_pda: AccountInfo<'info>,
+    collection_mint: AccountInfo<'info>,
+    collection_metadata: AccountInfo<'info>,
+    collection_edition: AccountInfo<'info>,
+    bubblegum_signer: AccountInfo<'info>,
+    log_wrapper: AccountInfo<'info>,
+    compression_program: AccountInfo<'info>,
+    token_metadata_program: AccountInfo<'info>,
+    system_program: AccountInfo<'info>,
+}
+
+/// Mint one placeholder leaf into the verified collection.
+/// The collection config PDA signs as tree delegate, collection authority and creator.
+fn mint_placeholder_leaf<'info>(
+    config: &Account<'info, CollectionConfig>,
+    accounts: MintLeafAccounts<'info>,
+    mint_index: u64,
+    config_bump: u8,
+) -> Result<()> {
+    let seeds = config.signer_seeds(config_bump);
+    let signer_seeds = &[&seeds.as_seeds()[..]];
+
+    // Mint compressed NFT using Bubblegum
+    MintToCollectionV1CpiBuilder::new(&accounts.bubblegum_program)
+        .tree_config(&accounts.tree_config)
+        .leaf_owner(&accounts.leaf_owner)
+        .leaf_delegate(&accounts.leaf_owner)
+        .merkle_tree(&accounts.merkle_tree)
+        .payer(&accounts.payer)
+        .tree_creator_or_delegate(&accounts.collection_config)
+        .collection_authority(&accounts.collection_config)
+        .collection_authority_record_pda(Some(&accounts.collection_authority_record_pda))
+        .collection_mint(&accounts.collection_mint)
+        .collection_metadata(&accounts.collection_metadata)
+        .collection_edition(&accounts.collection_edition)
+        .bubblegum_signer(&accounts.bubblegum_signer)
+        .log_wrapper(&accounts.log_wrapper)
+        .compression_program(&accounts.compression_program)
+        .token_metadata_program(&accounts.token_metadata_program)
+        .system_program(&accounts.system_program)
+        .metadata(placeholder_metadata_args(config, mint_index))
+        .invoke_signed(signer_seeds)?;
+
+    Ok(())
+}
+
+/// Mint a standard SPL NFT (decimals 0, supply 1) to the payer with analos-metadata.
+/// The mint is a PDA of the collection config, which signs as mint and update
+/// authority; the mint authority is revoked after minting. Returns the NFT mint.
+fn mint_standard_nft<'info>(
+    accounts: &MintPlaceholder<'info>,
+    mint_index: u64,
+    config_bump: u8,
+) -> Result<Pubkey> {
+    let config = &accounts.collection_config;
+    let payer = accounts.payer.to_account_info();
+    let system_program = accounts.system_program.to_account_info();
+    let token_program = required_account(&accounts.token_program)?;
+    let nft_mint = required_account(&accounts.nft_mint)?;
+    let nft_token_account = required_account(&accounts.nft_token_account)?;
+
+    let config_key = config.key();
+    let mint_index_bytes = mint_index.to_le_bytes();
+    let (expected_mint, mint_bump) = Pubkey::find_program_address(
+        &[b"nft_mint", config_key.as_ref(), &mint_index_bytes],
+        &crate::ID,
+    );
+    require!(nft_mint.key() == expected_mint, ErrorCode::InvalidNftMint);
+
+    // Create and initialize the mint at its PDA
+    create_pda_account(
+        &payer,
+        &nft_mint,
+        &system_program,
+        Mint::LEN,
+        &token::ID,
+        &[b"nft_mint", config_key.as_ref(), &mint_index_bytes, &[mint_bump]],
+    )?;
+    token::initialize_mint2(
+        CpiContext::new(
+            token_program.clone(),
+            InitializeMint2 { mint: nft_mint.clone() },
+        ),
+        0,
+        &config_key,
+        None,
+    )?;
+
+    associated_token::create(CpiContext::new(
+        required_account(&accounts.associated_token_program)?,
+        Create {
+            payer: payer.clone(),
+            associated_token: nft_token_account.clone(),
+            authority: payer.clone(),
+            mint: nft_mint.clone(),
+            system_program: system_program.clone(),
+            token_program: token_program.clone(),
+        },
+    ))?;
+
+    let seeds = config.signer_seeds(config_bump);
+    let signer_seeds = &[&seeds.as_seeds()[..]];
+
+    mint_to(
+        CpiContext::new_with_signer(
+            token_program.clone(),
+            MintTo {
+                mint: nft_mint.clone(),
+                to: nft_token_account,
+                authority: config.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        1,
+    )?;
+
+    // Revoke the mint authority so supply stays at 1
+    token::set_authority(
+        CpiContext::new_with_signer(
+            token_program.clone(),
+            SetAuthority {
+                current_authority: config.to_account_info(),
+                account_or_mint: nft_mint.clone(),
+            },
+            signer_seeds,
+        ),
+        AuthorityType::MintTokens,
+        None,
+    )?;
+
+    let metadata_accounts = CreateMetadata {
+        metadata: required_account(&accounts.nft_metadata)?,
+        mint: nft_mint,
+        update_authority: config.to_account_info(),
+        payer,
+        system_program,
+        token_program,
+    };
+    create_metadata(
+        CpiContext::new_with_signer(
+            required_account(&accounts.analos_metadata_program)?,
+            metadata_accounts,
+            signer_seeds,
+        ),
+        format!("{} Mystery #{}", config.collection_name, mint_index),
+        config.collection_symbol.clone(),
+        config.placeholder_uri.clone(),
+    )?;
+
+    Ok(expected_mint)
+}
+
+/// Revealed metadata URI for `mint_index`
+fn revealed_uri(config: &CollectionConfig, mint_index: u64) -> String {
+    format!(
+        "{}/{}.json",
+        config.revealed_base_uri.trim_end_matches('/'),
+        mint_index
+    )
+}
+
+/// Whether the longest item name, `"<name> Mystery #<index>"`, fits the
+/// 32-character name cap of Bubblegum and analos-metadata
+fn item_name_fits(collection_name: &str, max_supply: u64) -> bool {
+    let index_len = max_supply.saturating_sub(1).to_string().len();
+    collection_name.len() + " Mystery #".len() + index_len <= MAX_NAME_LENGTH
+}
+
+/// Length of the longest `/<index>.json` suffix `revealed_uri` appends for a
+/// collection of `max_supply` items
+fn revealed_uri_suffix_len(max_supply: u64) -> usize {
+    "/".len() + max_supply.saturating_sub(1).to_string().len() + ".json".len()
+}
+
+/// Rarity score (0-99) of `mint_index`, derived from the finalized reveal seed
+fn rarity_score(global_seed: &[u8; 32], mint_index: u64) -> u64 {
+    let rarity_hash = keccak::hashv(&[global_seed, &mint_index.to_le_bytes()]);
+    u64::from_le_bytes(rarity_hash.to_bytes()[..8].try_into().unwrap()) % 100
+}
+
+/// Unwrap an optional account required by the collection's mint mode
+fn required_account<'info, T: ToAccountInfo<'info>>(account: &Option<T>) -> Result<AccountInfo<'info>> {
+    account
+        .as_ref()
+        .map(|account| account.to_account_info())
+        .ok_or_else(|| error!(ErrorCode::MissingModeAccount))
+}
+
+/// Accounts for Bubblegum's `create_tree_config`
+struct CreateTreeAccounts<'info> {
+    bubblegum_program: AccountInfo<'info>,
+    tree_config: AccountInfo<'info>,
+    merkle_tree: AccountInfo<'info>,
+    payer: AccountInfo<'info>,
+    tree_creator: AccountInfo<'info>,
+    log_wrapper: AccountInfo<'info>,
+    compression_program: AccountInfo<'info>,
+    system_program: AccountInfo<'info>,
+}
+
+/// Validate tree parameters against the pre-allocated tree account, then create
+/// the tree through Bubblegum with the collection config PDA as tree creator
+fn create_collection_tree(
+    accounts: CreateTreeAccounts,
+    signer_seeds: &[&[&[u8]]],
+    tree_params: &TreeParams,
+) -> Result<()> {
+    require!(
+        tree_params.max_depth > 0
+            && tree_params.max_depth <= MAX_TREE_DEPTH
+            && tree_params.canopy_depth <= MAX_CANOPY_DEPTH
+            && tree_params.canopy_depth < tree_params.max_depth,
+        ErrorCode::InvalidTreeParams
+    );
+    require!(
+        accounts.merkle_tree.data_len() == merkle_tree_account_size(tree_params),
+        ErrorCode::InvalidTreeParams
+    );
+
+    CreateTreeConfigCpiBuilder::new(&accounts.bubblegum_program)
+        .tree_config(&accounts.tree_config)
+        .merkle_tree(&accounts.merkle_tree)
+        .payer(&accounts.payer)
+        .tree_creator(&accounts.tree_creator)
+        .log_wrapper(&accounts.log_wrapper)
+        .compression_program(&accounts.compression_program)
+        .system_program(&accounts.system_program)
+        .max_depth(tree_params.max_depth)
+        .max_buffer_size(tree_params.max_buffer_size)
+        .public(false)
+        .invoke_signed(signer_seeds)?;
+
+    Ok(())
+}
+
+/// Account size of a concurrent Merkle tree: header, tree (sequence number,
+/// active index, buffer size, change log buffer, rightmost proof) and canopy
+fn merkle_tree_account_size(tree_params: &TreeParams) -> usize {
+    let depth = tree_params.max_depth as usize;
+    let path_size = 32 * depth + 40; // Nodes plus root/leaf, index and padding
+    let tree_size = 24 + (tree_params.max_buffer_size as usize + 1) * path_size;
+    let canopy_size = ((1usize << (tree_params.canopy_depth + 1)) - 2) * 32;
+    CONCURRENT_MERKLE_TREE_HEADER_SIZE_V1 + tree_size + canopy_size
+}
+
+/// Check `merkle_tree` is the collection's active tree with room for `quantity`
+/// more leaves. Returns whether the mint fills the tree.
+///
+/// Batches don't span trees: one larger than the active tree's remaining
+/// capacity fails, and the caller mints the remainder first.
+fn check_active_tree(
+    config: &CollectionConfig,
+    merkle_tree: &AccountInfo,
+    tree_config: &AccountInfo,
+    quantity: u64,
+) -> Result<bool> {
+    let active_tree = config
+        .merkle_trees
+        .get(config.active_tree as usize)
+        .ok_or(ErrorCode::NoTreeCapacity)?;
+    require!(
+        merkle_tree.key() == *active_tree,
+        ErrorCode::InvalidMerkleTree
+    );
+    let tree_config = load_tree_config(tree_config)?;
+    let remaining = tree_config
+        .total_mint_capacity
+        .saturating_sub(tree_config.num_minted);
+    require!(remaining > 0, ErrorCode::NoTreeCapacity);
+    if quantity > remaining {
+        msg!("Active tree has {} leaves left; mint at most that many", remaining);
+        return err!(ErrorCode::BatchExceedsTreeCapacity);
+    }
+    Ok(quantity == remaining)
+}
+
+/// Asset id of the next leaf minted into `merkle_tree`
+fn next_asset_id(tree_config: &AccountInfo, merkle_tree: &AccountInfo) -> Result<Pubkey> {
+    let tree_config = load_tree_config(tree_config)?;
+    Ok(get_asset_id(&merkle_tree.key(), tree_config.num_minted))
+}
+
+/// Deserialize a Bubblegum tree config, checking it's owned by Bubblegum
+fn load_tree_config(tree_config: &AccountInfo) -> Result<TreeConfig> {
+    require_keys_eq!(*tree_config.owner, mpl_bubblegum::ID, ErrorCode::InvalidMerkleTree);
+    TreeConfig::try_from(tree_config).map_err(|_| error!(ErrorCode::InvalidMerkleTree))
+}
+
+/// Create and write the MintRecord PDA for `record.mint_index`, paid by `payer`
+fn create_mint_record<'info>(
+    config_key: Pubkey,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    record_info: &AccountInfo<'info>,
+    record: MintRecord,
+) -> Result<()> {
+    let mint_index_bytes = record.mint_index.to_le_bytes();
+    let (expected_key, bump) = Pubkey::find_program_address(
+        &[b"mint_record", config_key.as_ref(), &mint_index_bytes],
+        &crate::ID,
+    );
+    require!(record_info.key() == expected_key, ErrorCode::InvalidMintRecord);
+
+    create_pda_account(
+        payer,
+        record_info,
+        system_program,
+        8 + MintRecord::INIT_SPACE,
+        &crate::ID,
+        &[b"mint_record", config_key.as_ref(), &mint_index_bytes, &[bump]],
+    )?;
+
+    let mut data = record_info.try_borrow_mut_data()?;
+    record.try_serialize(&mut &mut data[..])
+}
+
+/// Credit `referrer` in its ReferralStats PDA (`[b"referral", collection_config, referrer]`),
+/// creating it on the first referral. Escrowed lamport rewards are recorded as pending.
+fn record_referral<'info>(
+    accounts: &MintPlaceholder<'info>,
+    referrer: Pubkey,
+    referral_payment: u64,
+    quantity: u64,
+    is_escrowed: bool,
+    now: i64,
+) -> Result<()> {
+    let stats_info = accounts
+        .referral_stats
+        .as_ref()
+        .ok_or(ErrorCode::InvalidReferralStats)?
+        .to_account_info();
+    let config_key = accounts.collection_config.key();
+    let (expected_key, bump) = Pubkey::find_program_address(
+        &[b"referral", config_key.as_ref(), referrer.as_ref()],
+        &crate::ID,
+    );
+    require!(stats_info.key() == expected_key, ErrorCode::InvalidReferralStats);
+
+    let mut stats = if stats_info.data_is_empty() {
+        create_pda_account(
+            &accounts.payer.to_account_info(),
+            &stats_info,
+            &accounts.system_program.to_account_info(),
+            8 + ReferralStats::INIT_SPACE,
+            &crate::ID,
+            &[b"referral", config_key.as_ref(), referrer.as_ref(), &[bump]],
+        )?;
+
+        ReferralStats {
+            collection_config: config_key,
+            referrer,
+            referred_mints: 0,
+            lamports_earned: 0,
+            pending_lamports: 0,
+            tokens_earned: 0,
+            last_referral_at: 0,
+        }
+    } else {
+        require!(stats_info.owner == &crate::ID, ErrorCode::InvalidReferralStats);
+        let data = stats_info.try_borrow_data()?;
+        ReferralStats::try_deserialize(&mut &data[..])?
+    };
+
+    stats.referred_mints += quantity;
+    match accounts.collection_config.payment_mint {
+        Some(_) => stats.tokens_earned += referral_payment,
+        None if is_escrowed => stats.pending_lamports += referral_payment,
+        None => stats.lamports_earned += referral_payment,
+    }
+    stats.last_referral_at = now;
+
+    let mut data = stats_info.try_borrow_mut_data()?;
+    stats.try_serialize(&mut &mut data[..])
+}
+
+/// Create a PDA account the way Anchor's `init` does: `create_account` when the
+/// address holds no lamports, otherwise top up to rent exemption, then `allocate`
+/// and `assign`, so pre-funding the address can't block its creation
+fn create_pda_account<'info>(
+    payer: &AccountInfo<'info>,
+    account: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    space: usize,
+    owner: &Pubkey,
+    signer_seeds: &[&[u8]],
+) -> Result<()> {
+    let rent_exempt_lamports = Rent::get()?.minimum_balance(space);
+    let current_lamports = account.lamports();
+
+    if current_lamports == 0 {
+        invoke_signed(
+            &system_instruction::create_account(
+                payer.key,
+                account.key,
+                rent_exempt_lamports,
+                space as u64,
+                owner,
+            ),
+            &[payer.clone(), account.clone(), system_program.clone()],
+            &[signer_seeds],
+        )?;
+        return Ok(());
+    }
+
+    transfer_lamports(
+        payer,
+        account,
+        system_program,
+        rent_exempt_lamports.saturating_sub(current_lamports),
+    )?;
+    invoke_signed(
+        &system_instruction::allocate(account.key, space as u64),
+        &[account.clone(), system_program.clone()],
+        &[signer_seeds],
+    )?;
+    invoke_signed(
+        &system_instruction::assign(account.key, owner),
+        &[account.clone(), system_program.clone()],
+        &[signer_seeds],
+    )?;
+    Ok(())
+}
+
+/// CPI into analos-token-launch `mint_tokens_for_nft` for a freshly minted asset.
+/// The collection config PDA signs as the linked NFT collection.
+fn mint_launch_tokens<'info>(
+    accounts: &MintPlaceholder<'info>,
+    asset_id: Pubkey,
+    config_bump: u8,
+) -> Result<()> {
+    let config = &accounts.collection_config;
+
+    let token_launch_config = accounts
+        .token_launch_config
+        .as_ref()
+        .ok_or(ErrorCode::MissingTokenLaunchAccount)?;
+    require!(
+        config.token_launch_config == Some(token_launch_config.key()),
+        ErrorCode::InvalidTokenLaunchAccount
+    );
+    let token_launch_program = accounts
+        .token_launch_program
+        .as_ref()
+        .ok_or(ErrorCode::MissingTokenLaunchAccount)?;
+    let launch_token_mint = accounts
+        .launch_token_mint
+        .as_ref()
+        .ok_or(ErrorCode::MissingTokenLaunchAccount)?;
+    let launch_token_escrow = accounts
+        .launch_token_escrow
+        .as_ref()
+        .ok_or(ErrorCode::MissingTokenLaunchAccount)?;
+    let token_program = accounts
+        .token_program
+        .as_ref()
+        .ok_or(ErrorCode::MissingTokenLaunchAccount)?;
+
+    let seeds = config.signer_seeds(config_bump);
+    let signer_seeds = &[&seeds.as_seeds()[..]];
+
+    let cpi_accounts = MintTokensForNFT {
+        token_launch_config: token_launch_config.to_account_info(),
+        nft_collection_config: config.to_account_info(),
+        token_mint: launch_token_mint.to_account_info(),
+        token_escrow: launch_token_escrow.to_account_info(),
+        token_program: token_program.to_account_info(),
+    };
+
+    let cpi_ctx = CpiContext::new_with_signer(
+        token_launch_program.to_account_info(),
+        cpi_accounts,
+        signer_seeds,
+    );
+
+    mint_tokens_for_nft(cpi_ctx, asset_id)
+}
+
+/// Check that the instruction before this one verifies `guard_signer`'s ed25519
+/// signature over `collection || minter || expiry` and that it hasn't expired
+fn check_mint_guard(
+    guard_signer: &Pubkey,
+    instructions_sysvar: &AccountInfo,
+    collection: &Pubkey,
+    minter: &Pubkey,
+    now: i64,
+) -> Result<()> {
+    let current_index = sysvar::instructions::load_current_index_checked(instructions_sysvar)?;
+    require!(current_index > 0, ErrorCode::MissingMintGuardSignature);
+    let ix = sysvar::instructions::load_instruction_at_checked(
+        current_index as usize - 1,
+        instructions_sysvar,
+    )?;
+    require!(
+        ix.program_id == ed25519_program::ID,
+        ErrorCode::MissingMintGuardSignature
+    );
+
+    // Exactly one signature, with key and message inline in the ed25519 instruction
+    let data = &ix.data;
+    require!(
+        data.len() >= ED25519_OFFSETS_START + ED25519_OFFSETS_SIZE && data[0] == 1,
+        ErrorCode::InvalidMintGuardSignature
+    );
+    let offsets: Vec<u16> = data[ED25519_OFFSETS_START..ED25519_OFFSETS_START + ED25519_OFFSETS_SIZE]
+        .chunks_exact(2)
+        .map(|chunk| u16::from_le_bytes([chunk[0], chunk[1]]))
+        .collect();
+    let (public_key_offset, message_offset, message_size) =
+        (offsets[2] as usize, offsets[4] as usize, offsets[5] as usize);
+    require!(
+        offsets[1] == u16::MAX && offsets[3] == u16::MAX && offsets[6] == u16::MAX,
+        ErrorCode::InvalidMintGuardSignature
+    );
+
+    let public_key = data
+        .get(public_key_offset..public_key_offset + 32)
+        .ok_or(ErrorCode::InvalidMintGuardSignature)?;
+    let message = data
+        .get(message_offset..message_offset + message_size)
+        .ok_or(ErrorCode::InvalidMintGuardSignature)?;
+    require!(
+        public_key == guard_signer.as_ref()
+            && message.len() == MINT_GUARD_MESSAGE_LEN
+            && message[..32] == collection.to_bytes()
+            && message[32..64] == minter.to_bytes(),
+        ErrorCode::InvalidMintGuardSignature
+    );
+
+    let expiry = i64::from_le_bytes(message[64..].try_into().unwrap());
+    require!(now <= expiry, ErrorCode::MintGuardExpired);
+
+    Ok(())
+}
+
+/// Look up `slot` in raw SlotHashes sysvar data (u64 length, then
+/// newest-first (slot, hash) entries)
+fn find_slot_hash(data: &[u8], slot: u64) -> Option<[u8; 32]> {
+    let len = u64::from_le_bytes(data.get(..8)?.try_into().ok()?) as usize;
+    data.get(8..)?
+        .chunks_exact(40)
+        .take(len)
+        .find(|entry| entry[..8] == slot.to_le_bytes())
+        .and_then(|entry| entry[8..].try_into().ok())
+}
+
+/// Validate royalty bps and creator shares (1-4 distinct creators summing to 100,
+/// none of them the collection config PDA)
+fn validate_creators(
+    royalty_basis_points: u16,
+    creators: &[CreatorShare],
+    config_key: &Pubkey,
+) -> Result<()> {
+    require!(
+        royalty_basis_points <= MAX_ROYALTY_BASIS_POINTS,
+        ErrorCode::InvalidRoyalty
+    );
+    require!(
+        !creators.is_empty() && creators.len() <= MAX_CREATORS,
+        ErrorCode::InvalidCreators
+    );
+    for (i, creator) in creators.iter().enumerate() {
+        require!(
+            creator.address != *config_key
+                && creators[..i].iter().all(|other| other.address != creator.address),
+            ErrorCode::DuplicateCreator
+        );
+    }
+    let total_share: u32 = creators.iter().map(|c| c.share as u32).sum();
+    require!(total_share == 100, ErrorCode::InvalidCreatorShares);
+    Ok(())
+}
+
+/// Validate a fee recipient list against the recipient and total bps caps
+fn validate_fee_recipients(fee_recipients: &[FeeRecipient]) -> Result<()> {
+    require!(
+        fee_recipients.len() <= MAX_FEE_RECIPIENTS,
+        ErrorCode::TooManyFeeRecipients
+    );
+    let total_bps: u32 = fee_recipients.iter().map(|r| r.basis_points as u32).sum();
+    require!(
+        total_bps <= MAX_TOTAL_FEE_BASIS_POINTS as u32,
+        ErrorCode::FeeTooHigh
+    );
+    Ok(())
+}
+
+/// Validate a payout split (empty, or 1-5 distinct wallets with bps summing to 10000)
+fn validate_payout_split(payout_split: &[PayoutShare]) -> Result<()> {
+    if payout_split.is_empty() {
+        return Ok(());
+    }
+    require!(
+        payout_split.len() <= MAX_PAYOUT_WALLETS,
+        ErrorCode::InvalidPayoutSplit
+    );
+    require!(
+        payout_split.iter().all(|share| share.basis_points > 0),
+        ErrorCode::InvalidPayoutSplit
+    );
+    let total_bps: u32 = payout_split.iter().map(|share| share.basis_points as u32).sum();
+    require!(total_bps == 10000, ErrorCode::InvalidPayoutSplit);
+    for (i, share) in payout_split.iter().enumerate() {
+        require!(
+            payout_split[..i].iter().all(|other| other.wallet != share.wallet),
+            ErrorCode::InvalidPayoutSplit
+        );
+    }
+    Ok(())
+}
+
+/// Payout shares for withdrawals: the configured split, or everything to the authority
+fn payout_shares(config: &CollectionConfig) -> Vec<PayoutShare> {
+    if config.payout_split.is_empty() {
+        vec![PayoutShare {
+            wallet: config.authority,
+            basis_points: 10000,
+        }]
+    } else {
+        config.payout_split.clone()
+    }
+}
+
+/// Lamports the collection config can pay out: its balance less the rent-exempt
+/// minimum, pending auction rebates and escrowed fees and referral rewards not
+/// yet paid out. Escrowed proceeds stay locked until the reveal threshold is met,
+/// or until every refund is claimed after a missed deadline (anything left over
+/// then, e.g. a donation, is the creator's).
+fn withdrawable_balance(config: &Account<CollectionConfig>, now: i64) -> Result<u64> {
+    let mut escrowed_payouts = config.pending_fee_lamports + config.pending_referral_lamports;
+    if config.reveal_deadline.is_some() && paid_supply(config) < config.reveal_threshold {
+        require!(
+            refunds_open(config, now) && config.refunds_paid >= config.escrow_total,
+            ErrorCode::ProceedsLocked
+        );
+        // The escrowed fees and referral rewards went back out as refunds
+        escrowed_payouts = 0;
+    }
+
+    let config_info = config.to_account_info();
+    let rent_exempt_minimum = Rent::get()?.minimum_balance(config_info.data_len());
+    Ok(config_info
+        .lamports()
+        .saturating_sub(rent_exempt_minimum)
+        .saturating_sub(auction_rebate_reserve(config, now))
+        .saturating_sub(escrowed_payouts))
+}
+
+/// Fee recipients as payout shares, weighted by their fee basis points
+fn fee_shares(fee_recipients: &[FeeRecipient]) -> Vec<PayoutShare> {
+    let total_bps: u32 = fee_recipients.iter().map(|r| r.basis_points as u32).sum();
+    if total_bps == 0 {
+        return Vec::new();
+    }
+    fee_recipients
+        .iter()
+        .map(|r| PayoutShare {
+            wallet: r.wallet,
+            basis_points: (r.basis_points as u32 * 10000 / total_bps) as u16,
+        })
+        .collect()
+}
+
+/// Move `amount` lamports from the program-owned `from` account to each payee by
+/// share; the last payee receives the rounding remainder
+fn pay_out_shares<'info>(
+    from: &AccountInfo<'info>,
+    shares: &[PayoutShare],
+    payee_accounts: &[AccountInfo<'info>],
+    amount: u64,
+) -> Result<Vec<u64>> {
+    require!(
+        payee_accounts.len() >= shares.len(),
+        ErrorCode::MissingPayoutWallet
+    );
+
+    let mut payee_amounts = Vec::with_capacity(shares.len());
+    let mut paid = 0u64;
+    for (i, (share, payee)) in shares.iter().zip(payee_accounts).enumerate() {
+        require!(payee.key() == share.wallet, ErrorCode::InvalidPayoutWallet);
+        let share_amount = if i == shares.len() - 1 {
+            amount - paid
+        } else {
+            (amount as u128 * share.basis_points as u128 / 10000) as u64
+        };
+        **from.try_borrow_mut_lamports()? -= share_amount;
+        **payee.try_borrow_mut_lamports()? += share_amount;
+        paid += share_amount;
+        payee_amounts.push(share_amount);
+    }
+
+    Ok(payee_amounts)
+}
+
+/// Split a payment into per-recipient fees and the remaining creator payment
+fn split_fees(amount: u64, fee_recipients: &[FeeRecipient]) -> (Vec<u64>, u64) {
+    let fee_amounts: Vec<u64> = fee_recipients
+        .iter()
+        .map(|r| (amount as u128 * r.basis_points as u128 / 10000) as u64)
+        .collect();
+    let creator_payment = amount - fee_amounts.iter().sum::<u64>();
+    (fee_amounts, creator_payment)
+}
+
+/// Compute the lamport price of `mint_index` for the collection's pricing mode
+fn resolve_lamport_price(
+    pricing_mode: PricingMode,
+    base_price_lamports: u64,
+    price_oracle: Option<&PriceOracle>,
+    mint_index: u64,
+    now: i64,
+) -> Result<u64> {
+    match pricing_mode {
+        PricingMode::Fixed => Ok(base_price_lamports),
+        PricingMode::DutchAuction { .. } => Ok(dutch_auction_price(pricing_mode, now)),
+        PricingMode::LinearCurve { .. } | PricingMode::ExponentialCurve { .. } => {
+            bonding_curve_price(pricing_mode, mint_index)
+        }
+        PricingMode::UsdPegged { price_usd } => {
+            let oracle = price_oracle.ok_or(ErrorCode::MissingPriceOracle)?;
+            require!(oracle.is_active, ErrorCode::OracleInactive);
+            require!(
+                now - oracle.last_update < MAX_PRICE_STALENESS_SECONDS,
+                ErrorCode::PriceTooStale
+            );
+            require!(oracle.los_price_usd > 0, ErrorCode::InvalidPrice);
+
+            // price_usd (6 decimals) / los_price_usd (6 decimals) * 10^9 (LOS decimals)
+            let lamports = price_usd as u128 * 10u128.pow(DECIMALS_LOS as u32)
+                / oracle.los_price_usd as u128;
+            u64::try_from(lamports).map_err(|_| error!(ErrorCode::MathOverflow))
+        }
+    }
+}
+
+/// Bonding curve price of `mint_index`, computed in checked u128 math
+fn bonding_curve_price(pricing_mode: PricingMode, mint_index: u64) -> Result<u64> {
+    let price = match pricing_mode {
+        PricingMode::LinearCurve {
+            start_price,
+            price_increment,
+        } => (price_increment as u128)
+            .checked_mul(mint_index as u128)
+            .and_then(|step| step.checked_add(start_price as u128)),
+        PricingMode::ExponentialCurve {
+            start_price,
+            growth_basis_points,
+        } => {
+            // start_price * (1 + growth)^mint_index in 1e12 fixed point
+            let base = CURVE_SCALE * (10000 + growth_basis_points as u128) / 10000;
+            checked_pow_scaled(base, mint_index)
+                .and_then(|factor| (start_price as u128).checked_mul(factor))
+                .map(|v| v / CURVE_SCALE)
+        }
+        _ => None,
+    };
+    price
+        .and_then(|p| u64::try_from(p).ok())
+        .ok_or_else(|| error!(ErrorCode::MathOverflow))
+}
+
+/// Raise a 1e12 fixed-point `base` to `exponent` by square-and-multiply
+fn checked_pow_scaled(mut base: u128, mut exponent: u64) -> Option<u128> {
+    let mut result = CURVE_SCALE;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = result.checked_mul(base)? / CURVE_SCALE;
+        }
+        exponent >>= 1;
+        if exponent > 0 {
+            base = base.checked_mul(base)? / CURVE_SCALE;
+        }
+    }
+    Some(result)
+}
+
+/// Current Dutch auction price: decays by `decay_step` every `decay_interval`
+/// seconds from `start_time`, never below `floor_price`
+fn dutch_auction_price(pricing_mode: PricingMode, now: i64) -> u64 {
+    match pricing_mode {
+        PricingMode::DutchAuction {
+            start_price,
+            floor_price,
+            start_time,
+            decay_interval,
+            decay_step,
+            ..
+        } => {
+            let elapsed = now.saturating_sub(start_time).max(0) as u64;
+            let steps = elapsed / decay_interval as u64;
+            start_price
+                .saturating_sub(steps.saturating_mul(decay_step))
+                .max(floor_price)
+        }
+        _ => 0,
+    }
+}
+
+/// Clearing price of an ended Dutch auction: the last price paid once the public
+/// supply (excluding the reserve) sells out, or the floor once the price has decayed to it
+fn auction_clearing_price(config: &CollectionConfig, now: i64) -> Option<u64> {
+    match config.pricing_mode {
+        PricingMode::DutchAuction { floor_price, .. } => {
+            let public_minted = config.current_supply - config.reserved_minted;
+            if public_minted >= config.max_supply - config.reserved_supply {
+                Some(config.last_mint_price)
+            } else if dutch_auction_price(config.pricing_mode, now) <= floor_price {
+                Some(floor_price)
+            } else {
+                None
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Whether `pricing_mode` is a Dutch auction that refunds down to the clearing price
+fn has_auction_rebates(pricing_mode: PricingMode) -> bool {
+    matches!(
+        pricing_mode,
+        PricingMode::DutchAuction { rebates_enabled: true, .. }
+    )
+}
+
+/// Lamports that must stay in the treasury to cover unclaimed auction rebates
+/// (worst case at the floor price while the auction is still running)
+///
+/// Rounds in the minters' favour, so it covers the sum of per-wallet rebates.
+fn auction_rebate_reserve(config: &CollectionConfig, now: i64) -> u64 {
+    match config.pricing_mode {
+        PricingMode::DutchAuction {
+            floor_price,
+            rebates_enabled: true,
+            ..
+        } => {
+            let clearing_price = auction_clearing_price(config, now).unwrap_or(floor_price);
+            auction_rebate(
+                config.auction_net_paid,
+                config.auction_clearing_units,
+                clearing_price,
+            )
+            .saturating_sub(config.auction_rebates_paid)
+        }
+        _ => 0,
+    }
+}
+
+/// Net-weighted item count of auction mints, `minted * net_paid / gross_paid`
+/// scaled by `REBATE_SCALE`. At clearing price `c` the creator keeps
+/// `c * units` of the net payment and the rest is rebated.
+fn auction_clearing_units(gross_paid: u64, net_paid: u64, minted: u64) -> u128 {
+    if gross_paid == 0 {
+        return 0;
+    }
+    (minted as u128)
+        .saturating_mul(net_paid as u128)
+        .saturating_mul(REBATE_SCALE)
+        / gross_paid as u128
+}
+
+/// Rebate owed from `net_paid` with `units` clearing units at `clearing_price`
+fn auction_rebate(net_paid: u64, units: u128, clearing_price: u64) -> u64 {
+    let kept = (clearing_price as u128)
+        .saturating_mul(units)
+        .saturating_add(REBATE_SCALE - 1)
+        / REBATE_SCALE;
+    (net_paid as u128).saturating_sub(kept) as u64
+}
+
+/// Items minted through paid mints (reserved mints excluded)
+fn paid_supply(config: &CollectionConfig) -> u64 {
+    config.current_supply - config.reserved_minted
+}
+
+/// Whether the reveal deadline passed before the threshold was met
+fn refunds_open(config: &CollectionConfig, now: i64) -> bool {
+    match config.reveal_deadline {
+        Some(deadline) => now >= deadline && paid_supply(config) < config.reveal_threshold,
+        None => false,
+    }
+}
+
+/// Check the payer against the collection's token gate, burning or transferring
+/// the gating tokens if the gate consumes them
+fn check_token_gate<'info>(
+    accounts: &MintPlaceholder<'info>,
+    token_gate: &TokenGate,
+    gate_accounts: &[AccountInfo<'info>],
+    quantity: u64,
+) -> Result<()> {
+    let payer = accounts.payer.to_account_info();
+    let is_consumed = token_gate.consumption != GateConsumption::Keep;
+
+    let holder_info = gate_accounts.first().ok_or(ErrorCode::MissingGateAccount)?;
+    let holder = Account::<TokenAccount>::try_from(holder_info)?;
+    require!(holder.owner == payer.key(), ErrorCode::InvalidGateAccount);
+
+    // Gating tokens are consumed per item minted
+    let (required_amount, next_account) = match token_gate.requirement {
+        GateRequirement::TokenBalance { mint, min_amount } => {
+            require!(holder.mint == mint, ErrorCode::InvalidGateAccount);
+            let required_amount = if is_consumed {
+                min_amount.checked_mul(quantity).ok_or(ErrorCode::MathOverflow)?
+            } else {
+                min_amount
+            };
+            (required_amount, 1)
+        }
+        GateRequirement::VerifiedCollection { collection_mint } => {
+            let metadata_info = gate_accounts.get(1).ok_or(ErrorCode::MissingGateAccount)?;
+            require!(
+                *metadata_info.owner == mpl_token_metadata::ID,
+                ErrorCode::InvalidGateAccount
+            );
+            let metadata = Metadata::safe_deserialize(&metadata_info.try_borrow_data()?)
+                .map_err(|_| error!(ErrorCode::InvalidGateAccount))?;
+            require!(metadata.mint == holder.mint, ErrorCode::InvalidGateAccount);
+            require!(
+                matches!(metadata.collection, Some(c) if c.verified && c.key == collection_mint),
+                ErrorCode::TokenGateNotMet
+            );
+            // One NFT gates one mint when consumed
+            require!(!is_consumed || quantity == 1, ErrorCode::InvalidQuantity);
+            (1, 2)
+        }
+    };
+    require!(holder.amount >= required_amount, ErrorCode::TokenGateNotMet);
+
+    if !is_consumed {
+        return Ok(());
+    }
+
+    let token_program = accounts
+        .token_program
+        .as_ref()
+        .ok_or(ErrorCode::MissingGateAccount)?
+        .to_account_info();
+    let target_info = gate_accounts
+        .get(next_account)
+        .ok_or(ErrorCode::MissingGateAccount)?;
+
+    match token_gate.consumption {
+        GateConsumption::Burn => {
+            require!(target_info.key() == holder.mint, ErrorCode::InvalidGateAccount);
+            let cpi_accounts = Burn {
+                mint: target_info.clone(),
+                from: holder_info.clone(),
+                authority: payer,
+            };
+            token::burn(CpiContext::new(token_program, cpi_accounts), required_amount)
+        }
+        GateConsumption::TransferToCreator => {
+            let target = Account::<TokenAccount>::try_from(target_info)?;
+            require!(
+                target.mint == holder.mint
+                    && target.owner == accounts.collection_config.authority,
+                ErrorCode::InvalidGateAccount
+            );
+            transfer_tokens(&token_program, holder_info, target_info, &payer, required_amount)
+        }
+        GateConsumption::Keep => Ok(()),
+    }
+}
+
+/// Collect a mint payment in lamports or in the collection's SPL payment mint,
+/// paying the creator treasury and each fee recipient. An escrowed lamport
+/// payment goes to the collection config in full, fees and referral included.
+fn collect_mint_payment<'info>(
+    accounts: &MintPlaceholder<'info>,
+    remaining_accounts: &[AccountInfo<'info>],
+    fee_recipients: &[FeeRecipient],
+    fee_amounts: &[u64],
+    creator_payment: u64,
+    referral_payment: u64,
+    is_escrowed: bool,
+) -> Result<()> {
+    let config = &accounts.collection_config;
+    let payer = accounts.payer.to_account_info();
+
+    require!(
+        remaining_accounts.len() >= fee_recipients.len(),
+        ErrorCode::MissingFeeRecipient
+    );
+
+    match config.payment_mint {
+        Some(payment_mint) => {
+            let token_program = accounts
+                .token_program
+                .as_ref()
+                .ok_or(ErrorCode::MissingPaymentAccount)?
+                .to_account_info();
+            let payer_token_account = accounts
+                .payer_token_account
+                .as_ref()
+                .ok_or(ErrorCode::MissingPaymentAccount)?;
+            let creator_token_account = accounts
+                .creator_token_account
+                .as_ref()
+                .ok_or(ErrorCode::MissingPaymentAccount)?;
+
+            require!(
+                payer_token_account.mint == payment_mint
+                    && payer_token_account.owner == payer.key(),
+                ErrorCode::InvalidPaymentAccount
+            );
+            require!(
+                creator_token_account.mint == payment_mint
+                    && creator_token_account.owner == config.authority,
+                ErrorCode::InvalidPaymentAccount
+            );
+
+            // Transfer payment to the creator treasury token account
+            transfer_tokens(
+                &token_program,
+                &payer_token_account.to_account_info(),
+                &creator_token_account.to_account_info(),
+                &payer,
+                creator_payment,
+            )?;
+
+            // Transfer the referral reward to the referrer's token account
+            if referral_payment > 0 {
+                let referrer_token_account = accounts
+                    .referrer_token_account
+                    .as_ref()
+                    .ok_or(ErrorCode::MissingPaymentAccount)?;
+                require!(
+                    referrer_token_account.mint == payment_mint
+                        && Some(referrer_token_account.owner)
+                            == accounts.referrer.as_ref().map(|referrer| referrer.key()),
+                    ErrorCode::InvalidPaymentAccount
+                );
+                transfer_tokens(
+                    &token_program,
+                    &payer_token_account.to_account_info(),
+                    &referrer_token_account.to_account_info(),
+                    &payer,
+                    referral_payment,
+                )?;
+            }
+
+            // Transfer each platform fee to the recipient's token account
+            for (i, recipient) in fee_recipients.iter().enumerate() {
+                let recipient_account = &remaining_accounts[i];
+                let recipient_token_account = Account::<TokenAccount>::try_from(recipient_account)?;
+                require!(
+                    recipient_token_account.owner == recipient.wallet
+                        && recipient_token_account.mint == payment_mint,
+                    ErrorCode::InvalidFeeRecipient
+                );
+                transfer_tokens(
+                    &token_program,
+                    &payer_token_account.to_account_info(),
+                    recipient_account,
+                    &payer,
+                    fee_amounts[i],
+                )?;
+            }
+        }
+        None => {
+            let system_program = accounts.system_program.to_account_info();
+
+            if is_escrowed {
+                let total_payment =
+                    creator_payment + referral_payment + fee_amounts.iter().sum::<u64>();
+                return transfer_lamports(
+                    &payer,
+                    &config.to_account_info(),
+                    &system_program,
+                    total_payment,
+                );
+            }
+
+            // Transfer payment to collection creator
+            transfer_lamports(&payer, &config.to_account_info(), &system_program, creator_payment)?;
+
+            // Transfer the referral reward to the referrer
+            if let Some(referrer) = &accounts.referrer {
+                transfer_lamports(&payer, referrer, &system_program, referral_payment)?;
+            }
+
+            // Transfer each platform fee
+            for (i, recipient) in fee_recipients.iter().enumerate() {
+                let recipient_account = &remaining_accounts[i];
+                require!(
+                    recipient_account.key() == recipient.wallet,
+                    ErrorCode::InvalidFeeRecipient
+                );
+                transfer_lamports(&payer, recipient_account, &system_program, fee_amounts[i])?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Transfer SPL tokens from an account owned by a signer
+fn transfer_tokens<'info>(
+    token_program: &AccountInfo<'info>,
+    from: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+    let cpi_accounts = Transfer {
+        from: from.clone(),
+        to: to.clone(),
+        authority: authority.clone(),
+    };
+    token::transfer(CpiContext::new(token_program.clone(), cpi_accounts), amount)
+}
+
+/// Transfer lamports from a signer through the system program
+fn transfer_lamports<'info>(
+    from: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+    let transfer_ix = system_instruction::transfer(from.key, to.key, amount);
+    invoke_signed(
+        &transfer_ix,
+        &[from.clone(), to.clone(), system_program.clone()],
+        &[],
+    )?;
+    Ok(())
+}
+
+/// Verify a keccak Merkle proof (sorted pairs) against an allowlist root
+fn verify_allowlist_proof(proof: &[[u8; 32]], root: [u8; 32], leaf: [u8; 32]) -> bool {
+    let mut computed = leaf;
+    for node in proof {
+        computed = if computed <= *node {
+            keccak::hashv(&[&computed, node]).to_bytes()
+        } else {
+            keccak::hashv(&[node, &computed]).to_bytes()
+        };
+    }
+    computed == root
+}
+
+// ========== ACCOUNTS ==========
+
+#[derive(Accounts)]
+#[instruction(collection_id: u64)]
+pub struct InitializeCollection<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + CollectionRegistry::INIT_SPACE,
+        seeds = [b"collection_registry", authority.key().as_ref()],
+        bump,
+    )]
+    pub collection_registry: Account<'info, CollectionRegistry>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + CollectionConfig::INIT_SPACE,
+        seeds = [b"collection", authority.key().as_ref(), &collection_id.to_le_bytes()],
+        bump,
+    )]
+    pub collection_config: Account<'info, CollectionConfig>,
+
+    /// CHECK: Pre-allocated tree account for compressed collections, initialized by Bubblegum
+    #[account(mut)]
+    pub merkle_tree: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Bubblegum tree config PDA, initialized by Bubblegum
+    #[account(mut)]
+    pub tree_config: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: This account is validated in the instruction
+    pub log_wrapper: Option<UncheckedAccount<'info>>,
+
+    pub collection_mint: Account<'info, Mint>,
+    /// CHECK: Bubblegum program
+    #[account(address = mpl_bubblegum::ID)]
+    pub bubblegum_program: Option<UncheckedAccount<'info>>,
+    pub compression_program: Option<Program<'info, SplAccountCompression>>,
+    pub system_program: Program<'info, System>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct MintReserved<'info> {
+    #[account(
+        mut,
+        seeds = [b"collection", collection_config.seed_authority.as_ref(), &collection_config.collection_id.to_le_bytes()],
+        bump,
+        has_one = authority,
+    )]
+    pub collection_config: Account<'info, CollectionConfig>,
+
+    /// CHECK: Validated against the collection's active tree
+    #[account(mut)]
+    pub merkle_tree: AccountInfo<'info>,
+
+    /// CHECK: Validated by Bubblegum program
+    #[account(mut)]
+    pub tree_config: AccountInfo<'info>,
+
+    /// CHECK: This account is validated in the instruction
+    pub log_wrapper: AccountInfo<'info>,
+
+    #[account(address = collection_config.collection_mint)]
+    pub collection_mint: Account<'info, Mint>,
+
+    /// CHECK: Collection metadata, validated by Bubblegum and Token Metadata
+    #[account(mut)]
+    pub collection_metadata: AccountInfo<'info>,
+
+    /// CHECK: Collection master edition, validated by Bubblegum and Token Metadata
+    pub collection_edition: AccountInfo<'info>,
+
+    /// CHECK: Token Metadata collection authority record delegating to the collection config PDA
+    pub collection_authority_record_pda: AccountInfo<'info>,
+
+    /// CHECK: Bubblegum collection CPI signer PDA
+    #[account(
+        seeds = [b"collection_cpi"],
+        bump,
+        seeds::program = mpl_bubblegum::ID,
+    )]
+    pub bubblegum_signer: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: Bubblegum program
+    #[account(address = mpl_bubblegum::ID)]
+    pub bubblegum_program: UncheckedAccount<'info>,
+    pub compression_program: Program<'info, SplAccountCompression>,
+    /// CHECK: Token Metadata program
+    #[account(address = mpl_token_metadata::ID)]
+    pub token_metadata_program: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> MintReserved<'info> {
+    /// Bubblegum accounts for minting a leaf to `leaf_owner`, paid by the authority
+    fn mint_to_collection_accounts(&self, leaf_owner: AccountInfo<'info>) -> MintLeafAccounts<'info> {
+        MintLeafAccounts {
+            bubblegum_program: self.bubblegum_program.to_account_info(),
+            tree_config: self.tree_config.to_account_info(),
+            leaf_owner,
+            merkle_tree: self.merkle_tree.to_account_info(),
+            payer: self.authority.to_account_info(),
+            collection_config: self.collection_config.to_account_info(),
+            collection_authority_record_pda: self.collection_authority_record_pda.to_account_info(),
+            collection_mint: self.collection_mint.to_account_info(),
+            collection_metadata: self.collection_metadata.to_account_info(),
+            collection_edition: self.collection_edition.to_account_info(),
+            bubblegum_signer: self.bubblegum_signer.to_account_info(),
+            log_wrapper: self.log_wrapper.to_account_info(),
+            compression_program: self.compression_program.to_account_info(),
+            token_metadata_program: self.token_metadata_program.to_account_info(),
+            system_program: self.system_program.to_account_info(),
+        }
+    }
+}
+
+#[derive(Accounts)]
+pub struct RegisterTree<'info> {
+    #[account(
+        mut,
+        seeds = [b"collection", collection_config.seed_authority.as_ref(), &collection_config.collection_id.to_le_bytes()],
+        bump,
+        has_one = authority,
+    )]
+    pub collection_config: Account<'info, CollectionConfig>,
+
+    /// CHECK: Pre-allocated tree account owned by the compression program, initialized by Bubblegum
+    #[account(mut)]
+    pub merkle_tree: AccountInfo<'info>,
+
+    /// CHECK: Bubblegum tree config PDA, initialized by Bubblegum
+    #[account(mut)]
+    pub tree_config: AccountInfo<'info>,
+
+    /// CHECK: This account is validated in the instruction
+    pub log_wrapper: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: Bubblegum program
+    #[account(address = mpl_bubblegum::ID)]
+    pub bubblegum_program: UncheckedAccount<'info>,
+    pub compression_program: Program<'info, SplAccountCompression>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct InitializePlatformConfig<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + PlatformConfig::INIT_SPACE,
+        seeds = [b"platform_config"],
+        bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, crate::program::AnalosNftLaunchpad>,
+
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(admin.key()) @ ErrorCode::Unauthorized,
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdatePlatformConfig<'info> {
+    #[account(
+        mut,
+        seeds = [b"platform_config"],
+        bump,
+        has_one = admin,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetCollectionFeeOverride<'info> {
+    #[account(
+        seeds = [b"platform_config"],
+        bump,
+        has_one = admin,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"collection", collection_config.seed_authority.as_ref(), &collection_config.collection_id.to_le_bytes()],
+        bump,
+    )]
+    pub collection_config: Account<'info, CollectionConfig>,
+
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct MintPlaceholder<'info> {
+    #[account(
+        mut,
+        seeds = [b"collection", collection_config.seed_authority.as_ref(), &collection_config.collection_id.to_le_bytes()],
+        bump,
+    )]
+    pub collection_config: Account<'info, CollectionConfig>,
+
+    #[account(
+        seeds = [b"platform_config"],
+        bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    /// CHECK: Compressed mode; validated against the collection's active tree
+    #[account(mut)]
+    pub merkle_tree: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Compressed mode; validated by Bubblegum program
+    #[account(mut)]
+    pub tree_config: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Compressed mode; validated by Bubblegum program
+    pub log_wrapper: Option<UncheckedAccount<'info>>,
+
+    #[account(address = collection_config.collection_mint)]
+    pub collection_mint: Account<'info, Mint>,
+
+    /// CHECK: Compressed mode; collection metadata, validated by Bubblegum and Token Metadata
+    #[account(mut)]
+    pub collection_metadata: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Compressed mode; collection master edition, validated by Bubblegum and Token Metadata
+    pub collection_edition: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Compressed mode; Token Metadata collection authority record delegating to the collection config PDA
+    pub collection_authority_record_pda: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Compressed mode; Bubblegum collection CPI signer PDA
+    #[account(
+        seeds = [b"collection_cpi"],
+        bump,
+        seeds::program = mpl_bubblegum::ID,
+    )]
+    pub bubblegum_signer: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Standard mode; NFT mint PDA `[b"nft_mint", collection_config, mint_index]`, created here
+    #[account(mut)]
+    pub nft_mint: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Standard mode; payer's associated token account for `nft_mint`, created here
+    #[account(mut)]
+    pub nft_token_account: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Standard mode; analos-metadata PDA for `nft_mint`, created by analos-metadata
+    #[account(mut)]
+    pub nft_metadata: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + WalletMintCounter::INIT_SPACE,
+        seeds = [b"wallet_mints", collection_config.key().as_ref(), payer.key().as_ref()],
+        bump,
+    )]
+    pub wallet_mint_counter: Account<'info, WalletMintCounter>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Payer's token account for SPL-priced collections
+    #[account(mut)]
+    pub payer_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// Creator treasury token account for SPL-priced collections
+    #[account(mut)]
+    pub creator_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// $LOS price oracle for USD-pegged collections
+    #[account(
+        seeds = [b"price_oracle"],
+        bump,
+        seeds::program = analos_price_oracle::ID,
+    )]
+    pub price_oracle: Option<Account<'info, PriceOracle>>,
+
+    /// CHECK: Referrer wallet credited for this mint; receives lamport referral rewards
+    #[account(mut)]
+    pub referrer: Option<UncheckedAccount<'info>>,
+
+    /// Referrer's token account for SPL-priced collections
+    #[account(mut)]
+    pub referrer_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// CHECK: ReferralStats PDA `[b"referral", collection_config, referrer]`, created on first referral
+    #[account(mut)]
+    pub referral_stats: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Bubblegum program
+    #[account(address = mpl_bubblegum::ID)]
+    pub bubblegum_program: Option<UncheckedAccount<'info>>,
+    pub compression_program: Option<Program<'info, SplAccountCompression>>,
+    /// CHECK: Token Metadata program
+    #[account(address = mpl_token_metadata::ID)]
+    pub token_metadata_program: Option<UncheckedAccount<'info>>,
+    pub associated_token_program: Option<Program<'info, AssociatedToken>>,
+    pub analos_metadata_program: Option<Program<'info, AnalosMetadata>>,
+    pub token_program: Option<Program<'info, Token>>,
+    pub system_program: Program<'info, System>,
+
+    /// Linked analos-token-launch config, required when the collection has one
+    #[account(
+        mut,
+        seeds = [b"token_launch_config", collection_config.key().as_ref()],
+        bump,
+        seeds::program = analos_token_launch::ID,
+    )]
+    pub token_launch_config: Option<Account<'info, TokenLaunchConfig>>,
+
+    /// CHECK: Launch token mint, validated by analos-token-launch
+    #[account(mut)]
+    pub launch_token_mint: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Launch token escrow, validated by analos-token-launch
+    #[account(mut)]
+    pub launch_token_escrow: Option<UncheckedAccount<'info>>,
+
+    pub token_launch_program: Option<Program<'info, AnalosTokenLaunch>>,
+
+    /// CHECK: Instructions sysvar, required when the collection has a mint guard
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions_sysvar: Option<UncheckedAccount<'info>>,
+}
+
+impl<'info> MintPlaceholder<'info> {
+    /// Bubblegum accounts for minting a leaf to the payer
+    fn mint_to_collection_accounts(&self) -> Result<MintLeafAccounts<'info>> {
+        Ok(MintLeafAccounts {
+            bubblegum_program: required_account(&self.bubblegum_program)?,
+            tree_config: required_account(&self.tree_config)?,
+            leaf_owner: self.payer.to_account_info(),
+            merkle_tree: required_account(&self.merkle_tree)?,
+            payer: self.payer.to_account_info(),
+            collection_config: self.collection_config.to_account_info(),
+            collection_authority_record_pda: required_account(&self.collection_authority_record_pda)?,
+            collection_mint: self.collection_mint.to_account_info(),
+            collection_metadata: required_account(&self.collection_metadata)?,
+            collection_edition: required_account(&self.collection_edition)?,
+            bubblegum_signer: required_account(&self.bubblegum_signer)?,
+            log_wrapper: required_account(&self.log_wrapper)?,
+            compression_program: required_account(&self.compression_program)?,
+            token_metadata_program: required_account(&self.token_metadata_program)?,
+            system_program: self.system_program.to_account_info(),
+        })
+    }
+}
+
+#[derive(Accounts)]
+pub struct FinalizeRevealSeed<'info> {
+    #[account(
+        mut,
+        seeds = [b"collection", collection_config.seed_authority.as_ref(), &collection_config.collection_id.to_le_bytes()],
+        bump,
+    )]
+    pub collection_config: Account<'info, CollectionConfig>,
+
+    /// CHECK: SlotHashes sysvar, parsed manually to avoid deserializing every entry
+    #[account(address = sysvar::slot_hashes::ID)]
+    pub slot_hashes: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct RevealCollection<'info> {
+    #[account(
+        mut,
+        seeds = [b"collection", collection_config.seed_authority.as_ref(), &collection_config.collection_id.to_le_bytes()],
+        bump,
+        has_one = authority,
+    )]
+    pub collection_config: Account<'info, CollectionConfig>,
+
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateNftMetadata<'info> {
+    #[account(
+        seeds = [b"collection", collection_config.seed_authority.as_ref(), &collection_config.collection_id.to_le_bytes()],
+        bump,
+        has_one = authority,
+    )]
+    pub collection_config: Account<'info, CollectionConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"mint_record", collection_config.key().as_ref(), &mint_record.mint_index.to_le_bytes()],
+        bump,
+    )]
+    pub mint_record: Account<'info, MintRecord>,
+
+    /// CHECK: Validated by Bubblegum program
+    #[account(mut)]
+    pub merkle_tree: AccountInfo<'info>,
+
+    /// CHECK: Validated by Bubblegum program
+    #[account(mut)]
+    pub tree_config: AccountInfo<'info>,
+
+    /// CHECK: Current owner of the leaf, verified against the proof by Bubblegum
+    pub leaf_owner: AccountInfo<'info>,
+
+    /// CHECK: Current delegate of the leaf, verified against the proof by Bubblegum
+    pub leaf_delegate: AccountInfo<'info>,
+
+    #[account(address = collection_config.collection_mint)]
+    pub collection_mint: Account<'info, Mint>,
+
+    /// CHECK: Collection metadata, validated by Bubblegum and Token Metadata
+    pub collection_metadata: AccountInfo<'info>,
+
+    /// CHECK: Token Metadata collection authority record delegating to the collection config PDA
+    pub collection_authority_record_pda: AccountInfo<'info>,
+
+    /// CHECK: This account is validated in the instruction
+    pub log_wrapper: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: Bubblegum program
+    #[account(address = mpl_bubblegum::ID)]
+    pub bubblegum_program: UncheckedAccount<'info>,
+    pub compression_program: Program<'info, SplAccountCompression>,
+    /// CHECK: Token Metadata program
+    #[account(address = mpl_token_metadata::ID)]
+    pub token_metadata_program: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawFunds<'info> {
+    #[account(
+        mut,
+        seeds = [b"collection", collection_config.seed_authority.as_ref(), &collection_config.collection_id.to_le_bytes()],
+        bump,
+        has_one = authority,
+    )]
+    pub collection_config: Account<'info, CollectionConfig>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct Distribute<'info> {
+    #[account(
+        mut,
+        seeds = [b"collection", collection_config.seed_authority.as_ref(), &collection_config.collection_id.to_le_bytes()],
+        bump,
+    )]
+    pub collection_config: Account<'info, CollectionConfig>,
+
+    pub cranker: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetPayoutSplit<'info> {
+    #[account(
+        mut,
+        seeds = [b"collection", collection_config.seed_authority.as_ref(), &collection_config.collection_id.to_le_bytes()],
+        bump,
+        has_one = authority,
+    )]
+    pub collection_config: Account<'info, CollectionConfig>,
+
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct RevealStandardNft<'info> {
+    #[account(
+        seeds = [b"collection", collection_config.seed_authority.as_ref(), &collection_config.collection_id.to_le_bytes()],
+        bump,
+        has_one = authority,
+    )]
+    pub collection_config: Account<'info, CollectionConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"mint_record", collection_config.key().as_ref(), &mint_record.mint_index.to_le_bytes()],
+        bump,
+    )]
+    pub mint_record: Account<'info, MintRecord>,
+
+    /// CHECK: analos-metadata PDA of the record's NFT mint, updated by analos-metadata
+    #[account(
+        mut,
+        seeds = [b"metadata", mint_record.asset_id.as_ref()],
+        bump,
+        seeds::program = analos_metadata::ID,
+    )]
+    pub nft_metadata: UncheckedAccount<'info>,
+
+    pub authority: Signer<'info>,
+
+    pub analos_metadata_program: Program<'info, AnalosMetadata>,
+}
 
 #[derive(Accounts)]
-#[instruction(collection_name: String, collection_symbol: String)]
-pub struct InitializeCollection<'info> {
+pub struct ClaimAuctionRebate<'info> {
+    #[account(
+        mut,
+        seeds = [b"collection", collection_config.seed_authority.as_ref(), &collection_config.collection_id.to_le_bytes()],
+        bump,
+    )]
+    pub collection_config: Account<'info, CollectionConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"wallet_mints", collection_config.key().as_ref(), minter.key().as_ref()],
+        bump,
+    )]
+    pub wallet_mint_counter: Account<'info, WalletMintCounter>,
+
     #[account(mut)]
-    pub payer: Signer<'info>,
+    pub minter: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetRevealDeadline<'info> {
+    #[account(
+        mut,
+        seeds = [b"collection", collection_config.seed_authority.as_ref(), &collection_config.collection_id.to_le_bytes()],
+        bump,
+        has_one = authority,
+    )]
+    pub collection_config: Account<'info, CollectionConfig>,
+
+    pub authority: Signer<'info>,
+}
 
+#[derive(Accounts)]
+pub struct ClaimRefund<'info> {
     #[account(
-        init,
-        payer = payer,
-        space = 8 + CollectionConfig::INIT_SPACE,
-        seeds = [b"collection", authority.key().as_ref()],
+        mut,
+        seeds = [b"collection", collection_config.seed_authority.as_ref(), &collection_config.collection_id.to_le_bytes()],
         bump,
     )]
     pub collection_config: Account<'info, CollectionConfig>,
 
-    /// CHECK: This account is validated in the instruction
+    #[account(
+        mut,
+        seeds = [b"wallet_mints", collection_config.key().as_ref(), minter.key().as_ref()],
+        bump,
+    )]
+    pub wallet_mint_counter: Account<'info, WalletMintCounter>,
+
     #[account(mut)]
-    pub merkle_tree: AccountInfo<'info>,
+    pub minter: Signer<'info>,
+}
 
-    /// CHECK: This account is validated in the instruction
+#[derive(Accounts)]
+pub struct ReleaseEscrowedFees<'info> {
+    #[account(
+        mut,
+        seeds = [b"collection", collection_config.seed_authority.as_ref(), &collection_config.collection_id.to_le_bytes()],
+        bump,
+    )]
+    pub collection_config: Account<'info, CollectionConfig>,
+
+    #[account(
+        seeds = [b"platform_config"],
+        bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    pub cranker: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimReferralRewards<'info> {
+    #[account(
+        mut,
+        seeds = [b"collection", collection_config.seed_authority.as_ref(), &collection_config.collection_id.to_le_bytes()],
+        bump,
+    )]
+    pub collection_config: Account<'info, CollectionConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"referral", collection_config.key().as_ref(), referrer.key().as_ref()],
+        bump,
+    )]
+    pub referral_stats: Account<'info, ReferralStats>,
+
+    /// CHECK: Referrer wallet receiving the rewards, bound by the ReferralStats seeds
     #[account(mut)]
-    pub tree_config: AccountInfo<'info>,
+    pub referrer: UncheckedAccount<'info>,
 
-    /// CHECK: This account is validated in the instruction
-    pub tree_creator: AccountInfo<'info>,
+    pub cranker: Signer<'info>,
+}
 
-    /// CHECK: This account is validated in the instruction
-    pub log_wrapper: AccountInfo<'info>,
+#[derive(Accounts)]
+pub struct ProposeAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [b"collection", collection_config.seed_authority.as_ref(), &collection_config.collection_id.to_le_bytes()],
+        bump,
+        has_one = authority,
+    )]
+    pub collection_config: Account<'info, CollectionConfig>,
 
-    pub collection_mint: Account<'info, Mint>,
-    pub bubblegum_program: Program<'info, BubblegumProgram>,
-    pub compression_program: Program<'info, SplAccountCompression>,
-    pub system_program: Program<'info, System>,
     pub authority: Signer<'info>,
 }
 
 #[derive(Accounts)]
-pub struct MintPlaceholder<'info> {
+pub struct AcceptAuthority<'info> {
     #[account(
         mut,
-        seeds = [b"collection", collection_config.authority.as_ref()],
+        seeds = [b"collection", collection_config.seed_authority.as_ref(), &collection_config.collection_id.to_le_bytes()],
         bump,
     )]
     pub collection_config: Account<'info, CollectionConfig>,
 
-    /// CHECK: This account is validated in the instruction
-    #[account(mut)]
-    pub merkle_tree: AccountInfo<'info>,
+    pub new_authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CloseCollection<'info> {
+    #[account(
+        mut,
+        seeds = [b"collection", collection_config.seed_authority.as_ref(), &collection_config.collection_id.to_le_bytes()],
+        bump,
+        has_one = authority,
+        close = authority,
+    )]
+    pub collection_config: Account<'info, CollectionConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"collection_registry", collection_config.seed_authority.as_ref()],
+        bump,
+    )]
+    pub collection_registry: Account<'info, CollectionRegistry>,
 
-    /// CHECK: This account is validated in the instruction
     #[account(mut)]
-    pub tree_config: AccountInfo<'info>,
+    pub authority: Signer<'info>,
+}
 
-    /// CHECK: This account is validated in the instruction
-    pub tree_creator: AccountInfo<'info>,
+#[derive(Accounts)]
+pub struct PauseCollection<'info> {
+    #[account(
+        mut,
+        seeds = [b"collection", collection_config.seed_authority.as_ref(), &collection_config.collection_id.to_le_bytes()],
+        bump,
+        has_one = authority,
+    )]
+    pub collection_config: Account<'info, CollectionConfig>,
 
-    /// CHECK: This account is validated in the instruction
-    pub log_wrapper: AccountInfo<'info>,
+    pub authority: Signer<'info>,
+}
 
-    #[account(mut)]
-    pub payer: Signer<'info>,
+#[derive(Accounts)]
+pub struct SetMintPhases<'info> {
+    #[account(
+        mut,
+        seeds = [b"collection", collection_config.seed_authority.as_ref(), &collection_config.collection_id.to_le_bytes()],
+        bump,
+        has_one = authority,
+    )]
+    pub collection_config: Account<'info, CollectionConfig>,
 
-    pub bubblegum_program: Program<'info, BubblegumProgram>,
-    pub compression_program: Program<'info, SplAccountCompression>,
-    pub system_program: Program<'info, System>,
+    pub authority: Signer<'info>,
 }
 
 #[derive(Accounts)]
-pub struct RevealCollection<'info> {
+pub struct SetPaymentMint<'info> {
     #[account(
         mut,
-        seeds = [b"collection", authority.key().as_ref()],
+        seeds = [b"collection", collection_config.seed_authority.as_ref(), &collection_config.collection_id.to_le_bytes()],
         bump,
         has_one = authority,
     )]
@@ -478,79 +3809,352 @@ pub struct RevealCollection<'info> {
 }
 
 #[derive(Accounts)]
-pub struct UpdateNftMetadata<'info> {
+pub struct SetTokenGate<'info> {
     #[account(
-        seeds = [b"collection", collection_config.authority.as_ref()],
+        mut,
+        seeds = [b"collection", collection_config.seed_authority.as_ref(), &collection_config.collection_id.to_le_bytes()],
         bump,
+        has_one = authority,
     )]
     pub collection_config: Account<'info, CollectionConfig>,
 
-    /// CHECK: This account is validated in the instruction
-    pub merkle_tree: AccountInfo<'info>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetMintGuard<'info> {
+    #[account(
+        mut,
+        seeds = [b"collection", collection_config.seed_authority.as_ref(), &collection_config.collection_id.to_le_bytes()],
+        bump,
+        has_one = authority,
+    )]
+    pub collection_config: Account<'info, CollectionConfig>,
 
     pub authority: Signer<'info>,
 }
 
 #[derive(Accounts)]
-pub struct WithdrawFunds<'info> {
+pub struct SetReferralBps<'info> {
     #[account(
         mut,
-        seeds = [b"collection", authority.key().as_ref()],
+        seeds = [b"collection", collection_config.seed_authority.as_ref(), &collection_config.collection_id.to_le_bytes()],
+        bump,
+        has_one = authority,
+    )]
+    pub collection_config: Account<'info, CollectionConfig>,
+
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetTokenLaunch<'info> {
+    #[account(
+        mut,
+        seeds = [b"collection", collection_config.seed_authority.as_ref(), &collection_config.collection_id.to_le_bytes()],
+        bump,
+        has_one = authority,
+    )]
+    pub collection_config: Account<'info, CollectionConfig>,
+
+    /// Token launch config created for this collection; omit to unlink
+    #[account(
+        seeds = [b"token_launch_config", collection_config.key().as_ref()],
+        bump,
+        seeds::program = analos_token_launch::ID,
+    )]
+    pub token_launch_config: Option<Account<'info, TokenLaunchConfig>>,
+
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetPricingMode<'info> {
+    #[account(
+        mut,
+        seeds = [b"collection", collection_config.seed_authority.as_ref(), &collection_config.collection_id.to_le_bytes()],
+        bump,
+        has_one = authority,
+    )]
+    pub collection_config: Account<'info, CollectionConfig>,
+
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateRoyalties<'info> {
+    #[account(
+        mut,
+        seeds = [b"collection", collection_config.seed_authority.as_ref(), &collection_config.collection_id.to_le_bytes()],
+        bump,
+        has_one = authority,
+    )]
+    pub collection_config: Account<'info, CollectionConfig>,
+
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    #[account(
+        mut,
+        seeds = [b"collection", collection_config.seed_authority.as_ref(), &collection_config.collection_id.to_le_bytes()],
+        bump,
+        has_one = authority,
+    )]
+    pub collection_config: Account<'info, CollectionConfig>,
+
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct LockConfig<'info> {
+    #[account(
+        mut,
+        seeds = [b"collection", collection_config.seed_authority.as_ref(), &collection_config.collection_id.to_le_bytes()],
         bump,
         has_one = authority,
     )]
     pub collection_config: Account<'info, CollectionConfig>,
 
-    #[account(mut)]
     pub authority: Signer<'info>,
 }
 
-#[derive(Accounts)]
-pub struct PauseCollection<'info> {
-    #[account(
-        mut,
-        seeds = [b"collection", authority.key().as_ref()],
-        bump,
-        has_one = authority,
-    )]
-    pub collection_config: Account<'info, CollectionConfig>,
+// ========== STATE ==========
+
+#[account]
+#[derive(InitSpace)]
+pub struct CollectionConfig {
+    pub authority: Pubkey,
+    pub collection_id: u64,
+    pub max_supply: u64,
+    pub current_supply: u64,
+    pub price_lamports: u64,
+    pub reveal_threshold: u64,
+    pub is_revealed: bool,
+    pub is_paused: bool,
+    pub global_seed: [u8; 32], // keccak(seed_secret, slot hash, config), set by finalize_reveal_seed
+    pub collection_mint: Pubkey,
+    #[max_len(32)] // MAX_NAME_LENGTH
+    pub collection_name: String,
+    #[max_len(10)] // MAX_SYMBOL_LENGTH
+    pub collection_symbol: String,
+    #[max_len(200)] // MAX_URI_LENGTH
+    pub placeholder_uri: String,
+    #[max_len(200)] // MAX_URI_LENGTH
+    pub revealed_base_uri: String,
+    #[max_len(5)] // MAX_MINT_PHASES
+    pub mint_phases: Vec<MintPhase>,
+    #[max_len(5)] // MAX_FEE_RECIPIENTS
+    pub fee_override: Option<Vec<FeeRecipient>>,
+    pub payment_mint: Option<Pubkey>, // SPL payment mint (None = lamports)
+    pub payment_price: u64,           // Price in payment mint base units
+    pub pricing_mode: PricingMode,
+    pub royalty_basis_points: u16,
+    #[max_len(4)] // MAX_CREATORS
+    pub creators: Vec<CreatorShare>,
+    pub last_mint_price: u64,          // Unit price of the most recent mint
+    pub auction_net_paid: u64,         // Net creator lamports from a rebate-enabled Dutch auction
+    pub auction_clearing_units: u128,  // Sum of per-wallet clearing units (see auction_clearing_units)
+    pub auction_rebates_paid: u64,     // Rebates already claimed
+    pub reveal_deadline: Option<i64>,   // Refunds open if the threshold is missed by then
+    pub escrow_total: u64,              // Mint payments escrowed for refunds
+    pub refunds_paid: u64,              // Refunds already claimed
+    pub pending_fee_lamports: u64,      // Escrowed platform fees not yet released
+    pub pending_referral_lamports: u64, // Escrowed referral rewards not yet claimed
+    pub token_gate: Option<TokenGate>,
+    pub token_launch_config: Option<Pubkey>, // analos-token-launch config funded on each mint
+    pub seed_commitment: [u8; 32], // keccak(seed_secret), committed at init
+    pub seed_secret: [u8; 32],     // Revealed with the collection
+    pub seed_slot: u64,            // Slot whose hash is mixed into global_seed
+    pub is_seed_final: bool,
+    #[max_len(8)] // MAX_TREES
+    pub merkle_trees: Vec<Pubkey>,
+    pub active_tree: u8, // Index into merkle_trees; == len once every tree is full
+    pub reserved_supply: u64, // Authority-only budget within max_supply
+    pub reserved_minted: u64,
+    pub seed_authority: Pubkey, // Authority at creation; fixed PDA seed so `authority` can change
+    pub pending_authority: Option<Pubkey>, // Proposed authority awaiting acceptance
+    pub mint_mode: MintMode,
+    pub mint_guard_signer: Option<Pubkey>, // Co-signer required on every mint, if set
+    pub referral_bps: u16, // Share of the creator payment paid to referrers
+    #[max_len(5)] // MAX_PAYOUT_WALLETS
+    pub payout_split: Vec<PayoutShare>, // Empty pays the authority
+    pub is_locked: bool, // Set by lock_config; freezes price, supply and mint terms
+}
+
+impl CollectionConfig {
+    /// Seeds of the collection config PDA, for signing CPIs as the config
+    pub fn signer_seeds(&self, bump: u8) -> CollectionSeeds {
+        CollectionSeeds {
+            seed_authority: self.seed_authority,
+            collection_id: self.collection_id.to_le_bytes(),
+            bump: [bump],
+        }
+    }
+}
+
+/// Owned `[b"collection", seed_authority, collection_id, bump]` seeds
+pub struct CollectionSeeds {
+    seed_authority: Pubkey,
+    collection_id: [u8; 8],
+    bump: [u8; 1],
+}
+
+impl CollectionSeeds {
+    pub fn as_seeds(&self) -> [&[u8]; 4] {
+        [
+            b"collection",
+            self.seed_authority.as_ref(),
+            &self.collection_id,
+            &self.bump,
+        ]
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum MintMode {
+    /// Bubblegum compressed NFTs in the collection's Merkle trees
+    Compressed,
+    /// Standard SPL NFTs (decimals 0, supply 1) with analos-metadata
+    Standard,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, InitSpace)]
+pub struct TreeParams {
+    pub max_depth: u32,
+    pub max_buffer_size: u32,
+    pub canopy_depth: u32,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub struct TokenGate {
+    pub requirement: GateRequirement,
+    pub consumption: GateConsumption,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum GateRequirement {
+    /// Hold at least `min_amount` of `mint`
+    TokenBalance { mint: Pubkey, min_amount: u64 },
+    /// Hold an NFT verified as part of `collection_mint`
+    VerifiedCollection { collection_mint: Pubkey },
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum GateConsumption {
+    /// The gating token is only checked
+    Keep,
+    /// The gating token is burned on use
+    Burn,
+    /// The gating token is transferred to the collection authority on use
+    TransferToCreator,
+}
 
-    pub authority: Signer<'info>,
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct CreatorShare {
+    pub address: Pubkey,
+    pub share: u8, // Percent of royalties (all shares sum to 100)
 }
 
-#[derive(Accounts)]
-pub struct UpdateConfig<'info> {
-    #[account(
-        mut,
-        seeds = [b"collection", authority.key().as_ref()],
-        bump,
-        has_one = authority,
-    )]
-    pub collection_config: Account<'info, CollectionConfig>,
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum PricingMode {
+    /// `price_lamports` (or the active phase price)
+    Fixed,
+    /// USD price with 6 decimals, converted to lamports via the price oracle
+    UsdPegged { price_usd: u64 },
+    /// Price decays from `start_price` by `decay_step` every `decay_interval` seconds
+    DutchAuction {
+        start_price: u64,
+        floor_price: u64,
+        start_time: i64,
+        decay_interval: i64,
+        decay_step: u64,
+        rebates_enabled: bool,
+    },
+    /// Price rises by `price_increment` per item already minted
+    LinearCurve { start_price: u64, price_increment: u64 },
+    /// Price compounds by `growth_basis_points` per item already minted
+    ExponentialCurve { start_price: u64, growth_basis_points: u16 },
+}
 
-    pub authority: Signer<'info>,
+#[account]
+#[derive(InitSpace)]
+pub struct MintRecord {
+    pub collection_config: Pubkey,
+    pub mint_index: u64,
+    pub asset_id: Pubkey, // cNFT asset id (tree and leaf nonce), or the standard NFT mint
+    pub merkle_tree: Pubkey,
+    pub minter: Pubkey,
+    pub price_paid: u64,
+    pub minted_at: i64,
+    pub is_revealed: bool,
+    pub rarity_score: Option<u64>, // 0-99, set when the item is revealed
 }
 
-// ========== STATE ==========
+#[account]
+#[derive(InitSpace)]
+pub struct ReferralStats {
+    pub collection_config: Pubkey,
+    pub referrer: Pubkey,
+    pub referred_mints: u64,
+    pub lamports_earned: u64,
+    pub pending_lamports: u64, // Escrowed under a reveal deadline, claimable once the threshold is met
+    pub tokens_earned: u64, // In payment mint units, for SPL-priced collections
+    pub last_referral_at: i64,
+}
 
 #[account]
 #[derive(InitSpace)]
-pub struct CollectionConfig {
+pub struct CollectionRegistry {
     pub authority: Pubkey,
-    pub max_supply: u64,
-    pub current_supply: u64,
+    pub collection_count: u64, // Next collection_id
+    #[max_len(50)] // MAX_COLLECTIONS_PER_AUTHORITY
+    pub collections: Vec<Pubkey>,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct PlatformConfig {
+    pub admin: Pubkey,
+    #[max_len(5)] // MAX_FEE_RECIPIENTS
+    pub fee_recipients: Vec<FeeRecipient>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct FeeRecipient {
+    pub wallet: Pubkey,
+    pub basis_points: u16,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct PayoutShare {
+    pub wallet: Pubkey,
+    pub basis_points: u16, // Share of each withdrawal (all shares sum to 10000)
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct MintPhase {
+    pub start_time: i64,
+    pub end_time: i64,
     pub price_lamports: u64,
-    pub reveal_threshold: u64,
-    pub is_revealed: bool,
-    pub is_paused: bool,
-    pub global_seed: [u8; 32],
-    pub collection_mint: Pubkey,
-    #[max_len(32)]
-    pub collection_name: String,
-    #[max_len(10)]
-    pub collection_symbol: String,
-    #[max_len(200)]
-    pub placeholder_uri: String,
+    pub allowlist_root: Option<[u8; 32]>, // keccak root of allowlisted wallets
+    pub max_per_wallet: u64,               // 0 = unlimited
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct WalletMintCounter {
+    pub collection_config: Pubkey,
+    pub wallet: Pubkey,
+    pub total_minted: u64,
+    pub phase_mints: [u64; MAX_MINT_PHASES],
+    pub auction_paid: u64,
+    pub auction_net_paid: u64, // Creator share of auction_paid, after fees and referral
+    pub auction_minted: u64,
+    pub rebate_claimed: bool,
+    pub escrowed_lamports: u64, // Refundable payment receipt
+    pub refunded: bool,
 }
 
 // ========== EVENTS ==========
@@ -559,6 +4163,8 @@ pub struct CollectionConfig {
 pub struct CollectionInitializedEvent {
     pub collection_config: Pubkey,
     pub authority: Pubkey,
+    pub collection_id: u64,
+    pub mint_mode: MintMode,
     pub max_supply: u64,
     pub price_lamports: u64,
     pub reveal_threshold: u64,
@@ -570,6 +4176,17 @@ pub struct MintEvent {
     pub mint_index: u64,
     pub minter: Pubkey,
     pub merkle_tree: Pubkey,
+    pub is_reserved: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BatchMintEvent {
+    pub collection_config: Pubkey,
+    pub minter: Pubkey,
+    pub first_index: u64,
+    pub quantity: u64,
+    pub total_payment: u64,
     pub timestamp: i64,
 }
 
@@ -580,20 +4197,63 @@ pub struct RevealEvent {
     pub revealed_base_uri: String,
 }
 
+#[event]
+pub struct TreeRegisteredEvent {
+    pub collection_config: Pubkey,
+    pub merkle_tree: Pubkey,
+    pub tree_index: u8,
+    pub max_depth: u32,
+    pub max_buffer_size: u32,
+    pub canopy_depth: u32,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RevealSeedFinalizedEvent {
+    pub collection_config: Pubkey,
+    pub seed_secret: [u8; 32],
+    pub seed_slot: u64,
+    pub slot_hash: [u8; 32],
+    pub global_seed: [u8; 32],
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct MetadataUpdateEvent {
+    pub mint_index: u64,
+    pub leaf_owner: Pubkey,
     pub timestamp: i64,
     pub new_uri: String,
+    pub rarity_score: u64,
 }
 
 #[event]
 pub struct FeeCollectionEvent {
     pub mint_index: u64,
+    pub quantity: u64,
+    pub payment_mint: Option<Pubkey>,
     pub total_payment: u64,
+    pub effective_price: u64, // Average price charged per item
     pub creator_payment: u64,
-    pub platform_fee: u64,
-    pub buyback_fee: u64,
-    pub dev_fee: u64,
+    pub fee_recipients: Vec<Pubkey>,
+    pub fee_amounts: Vec<u64>,
+    pub total_fees: u64,
+    pub referrer: Option<Pubkey>,
+    pub referral_payment: u64, // Carved out of the creator payment
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PlatformConfigUpdatedEvent {
+    pub admin: Pubkey,
+    pub fee_recipients: Vec<FeeRecipient>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CollectionFeeOverrideEvent {
+    pub collection_config: Pubkey,
+    pub fee_override: Option<Vec<FeeRecipient>>,
     pub timestamp: i64,
 }
 
@@ -601,6 +4261,97 @@ pub struct FeeCollectionEvent {
 pub struct WithdrawEvent {
     pub amount: u64,
     pub authority: Pubkey,
+    pub payees: Vec<Pubkey>,
+    pub payee_amounts: Vec<u64>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PayoutDistributedEvent {
+    pub collection_config: Pubkey,
+    pub amount: u64,
+    pub payees: Vec<Pubkey>,
+    pub payee_amounts: Vec<u64>,
+    pub cranker: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PayoutSplitUpdatedEvent {
+    pub collection_config: Pubkey,
+    pub payout_split: Vec<PayoutShare>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AuctionRebateClaimedEvent {
+    pub collection_config: Pubkey,
+    pub minter: Pubkey,
+    pub clearing_price: u64,
+    pub rebate: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RevealDeadlineUpdatedEvent {
+    pub collection_config: Pubkey,
+    pub reveal_deadline: Option<i64>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RefundClaimedEvent {
+    pub collection_config: Pubkey,
+    pub minter: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct EscrowedFeesReleasedEvent {
+    pub collection_config: Pubkey,
+    pub amount: u64,
+    pub fee_recipients: Vec<Pubkey>,
+    pub fee_amounts: Vec<u64>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReferralRewardClaimedEvent {
+    pub collection_config: Pubkey,
+    pub referrer: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CollectionClosedEvent {
+    pub collection_config: Pubkey,
+    pub authority: Pubkey,
+    pub collection_id: u64,
+    pub current_supply: u64,
+    pub max_supply: u64,
+    pub reserved_minted: u64,
+    pub is_revealed: bool,
+    pub escrow_total: u64,
+    pub refunds_paid: u64,
+    pub lamports_reclaimed: u64, // Rent returned to the authority
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AuthorityProposedEvent {
+    pub collection_config: Pubkey,
+    pub authority: Pubkey,
+    pub pending_authority: Option<Pubkey>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AuthorityTransferredEvent {
+    pub collection_config: Pubkey,
+    pub previous_authority: Pubkey,
+    pub new_authority: Pubkey,
     pub timestamp: i64,
 }
 
@@ -610,10 +4361,91 @@ pub struct PauseEvent {
     pub timestamp: i64,
 }
 
+#[event]
+pub struct MintPhasesUpdatedEvent {
+    pub collection_config: Pubkey,
+    pub phase_count: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TokenGateUpdatedEvent {
+    pub collection_config: Pubkey,
+    pub token_gate: Option<TokenGate>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MintGuardUpdatedEvent {
+    pub collection_config: Pubkey,
+    pub mint_guard_signer: Option<Pubkey>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReferralBpsUpdatedEvent {
+    pub collection_config: Pubkey,
+    pub referral_bps: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TokenLaunchLinkedEvent {
+    pub collection_config: Pubkey,
+    pub token_launch_config: Option<Pubkey>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PaymentMintUpdatedEvent {
+    pub collection_config: Pubkey,
+    pub payment_mint: Option<Pubkey>,
+    pub payment_price: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PricingModeUpdatedEvent {
+    pub collection_config: Pubkey,
+    pub pricing_mode: PricingMode,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RoyaltiesUpdatedEvent {
+    pub collection_config: Pubkey,
+    pub royalty_basis_points: u16,
+    pub creators: Vec<CreatorShare>,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct ConfigUpdateEvent {
     pub new_price: Option<u64>,
     pub new_reveal_threshold: Option<u64>,
+    pub new_name: Option<String>,
+    pub new_symbol: Option<String>,
+    pub new_placeholder_uri: Option<String>,
+    pub new_max_supply: Option<u64>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ConfigLockedEvent {
+    pub collection_config: Pubkey,
+    pub collection_name: String,
+    pub collection_symbol: String,
+    pub placeholder_uri: String,
+    pub max_supply: u64,
+    pub reserved_supply: u64,
+    pub price_lamports: u64,
+    pub pricing_mode: PricingMode,
+    pub payment_mint: Option<Pubkey>,
+    pub payment_price: u64,
+    pub reveal_threshold: u64,
+    pub reveal_deadline: Option<i64>,
+    pub royalty_basis_points: u16,
+    pub referral_bps: u16,
     pub timestamp: i64,
 }
 
@@ -635,8 +4467,359 @@ pub enum ErrorCode {
     InsufficientFunds,
     #[msg("Invalid threshold value")]
     InvalidThreshold,
-    #[msg("Invalid max supply (max 16,384)")]
+    #[msg("Invalid max supply")]
     InvalidMaxSupply,
     #[msg("Unauthorized access")]
     Unauthorized,
+    #[msg("Invalid mint index")]
+    InvalidMintIndex,
+    #[msg("URI too long (max 200 characters)")]
+    UriTooLong,
+    #[msg("No mint phase is currently active")]
+    NoActivePhase,
+    #[msg("Wallet is not on the allowlist for this phase")]
+    NotOnAllowlist,
+    #[msg("Wallet mint limit reached for this phase")]
+    WalletLimitReached,
+    #[msg("Too many mint phases (max 5)")]
+    TooManyPhases,
+    #[msg("Invalid mint phase (phases must be ordered and non-overlapping)")]
+    InvalidPhase,
+    #[msg("Too many fee recipients (max 5)")]
+    TooManyFeeRecipients,
+    #[msg("Total fees exceed the 10% cap")]
+    FeeTooHigh,
+    #[msg("Missing fee recipient account")]
+    MissingFeeRecipient,
+    #[msg("Fee recipient account does not match configuration")]
+    InvalidFeeRecipient,
+    #[msg("Missing token account for SPL payment")]
+    MissingPaymentAccount,
+    #[msg("Token account does not match the payment mint or owner")]
+    InvalidPaymentAccount,
+    #[msg("Price oracle account required for USD-pegged pricing")]
+    MissingPriceOracle,
+    #[msg("Price oracle is inactive")]
+    OracleInactive,
+    #[msg("Price data is too stale (max 5 minutes)")]
+    PriceTooStale,
+    #[msg("Invalid price")]
+    InvalidPrice,
+    #[msg("Math overflow")]
+    MathOverflow,
+    #[msg("Invalid mint quantity (1-10 per batch)")]
+    InvalidQuantity,
+    #[msg("Collection id must be the next index in the authority registry")]
+    InvalidCollectionId,
+    #[msg("Authority registry is full (max 50 collections)")]
+    TooManyCollections,
+    #[msg("Invalid royalty (max 10000 basis points)")]
+    InvalidRoyalty,
+    #[msg("Invalid creators (1-4 creators; the collection config PDA takes Bubblegum's fifth slot)")]
+    InvalidCreators,
+    #[msg("Creator shares must sum to 100")]
+    InvalidCreatorShares,
+    #[msg("Minting has already started")]
+    MintingStarted,
+    #[msg("Invalid Dutch auction parameters")]
+    InvalidAuction,
+    #[msg("Auction rebates are not enabled")]
+    RebatesDisabled,
+    #[msg("Dutch auction has not ended")]
+    AuctionNotEnded,
+    #[msg("Auction rebate already claimed")]
+    RebateAlreadyClaimed,
+    #[msg("No auction rebate due")]
+    NoRebateDue,
+    #[msg("Reveal deadline passed before the threshold was met")]
+    RevealDeadlinePassed,
+    #[msg("Proceeds are locked until the reveal threshold is met")]
+    ProceedsLocked,
+    #[msg("Invalid reveal deadline")]
+    InvalidRevealDeadline,
+    #[msg("Refund escrow requires lamport payments")]
+    EscrowRequiresLamports,
+    #[msg("Refunds are not available")]
+    RefundsNotAvailable,
+    #[msg("Refund already claimed")]
+    RefundAlreadyClaimed,
+    #[msg("No refund due")]
+    NoRefundDue,
+    #[msg("Invalid bonding curve parameters")]
+    InvalidCurve,
+    #[msg("Invalid token gate")]
+    InvalidTokenGate,
+    #[msg("Missing token gate account")]
+    MissingGateAccount,
+    #[msg("Invalid token gate account")]
+    InvalidGateAccount,
+    #[msg("Token gate requirement not met")]
+    TokenGateNotMet,
+    #[msg("Missing token launch account")]
+    MissingTokenLaunchAccount,
+    #[msg("Invalid token launch account")]
+    InvalidTokenLaunchAccount,
+    #[msg("Seed secret does not match the commitment")]
+    InvalidSeedSecret,
+    #[msg("Reveal seed already finalized")]
+    SeedAlreadyFinalized,
+    #[msg("Seed slot not reached")]
+    SeedSlotNotReached,
+    #[msg("Seed slot hash not found")]
+    SlotHashNotFound,
+    #[msg("Reveal seed not finalized")]
+    SeedNotFinalized,
+    #[msg("Missing mint record account")]
+    MissingMintRecord,
+    #[msg("Invalid mint record account")]
+    InvalidMintRecord,
+    #[msg("Invalid Merkle tree parameters")]
+    InvalidTreeParams,
+    #[msg("Too many Merkle trees")]
+    TooManyTrees,
+    #[msg("Merkle tree is not the collection's active tree")]
+    InvalidMerkleTree,
+    #[msg("No Merkle tree capacity left")]
+    NoTreeCapacity,
+    #[msg("Invalid reserved supply")]
+    InvalidReservedSupply,
+    #[msg("Reserved supply exhausted")]
+    ReservedSupplyExhausted,
+    #[msg("Recipient accounts don't match recipients")]
+    InvalidRecipients,
+    #[msg("Account required by the collection's mint mode is missing")]
+    MissingModeAccount,
+    #[msg("Not supported for the collection's mint mode")]
+    UnsupportedMintMode,
+    #[msg("Invalid NFT mint account")]
+    InvalidNftMint,
+    #[msg("Mint guard signature instruction is missing")]
+    MissingMintGuardSignature,
+    #[msg("Mint guard signature doesn't match the guard signer, collection or minter")]
+    InvalidMintGuardSignature,
+    #[msg("Mint guard signature has expired")]
+    MintGuardExpired,
+    #[msg("Referral share exceeds the maximum")]
+    InvalidReferralBps,
+    #[msg("Invalid referrer")]
+    InvalidReferrer,
+    #[msg("Missing or invalid referral stats account")]
+    InvalidReferralStats,
+    #[msg("Payout split must be 1-5 distinct wallets with shares summing to 10000 bps")]
+    InvalidPayoutSplit,
+    #[msg("Payout wallet account is missing")]
+    MissingPayoutWallet,
+    #[msg("Payout wallet doesn't match the payout split")]
+    InvalidPayoutWallet,
+    #[msg("Escrowed proceeds may still be refunded")]
+    RefundsPending,
+    #[msg("Auction rebates are still owed")]
+    RebatesPending,
+    #[msg("Withdraw the collection balance before closing")]
+    BalanceNotWithdrawn,
+    #[msg("Name too long: item names (\"<name> Mystery #<index>\") are capped at 32 characters")]
+    NameTooLong,
+    #[msg("Symbol too long (max 10 characters)")]
+    SymbolTooLong,
+    #[msg("Collection config is locked")]
+    ConfigLocked,
+    #[msg("Creator addresses must be distinct and can't be the collection config")]
+    DuplicateCreator,
+    #[msg("Auction rebates can't be combined with a reveal deadline escrow")]
+    RebatesWithEscrow,
+    #[msg("Reveal threshold and max supply are fixed once escrowed minting starts")]
+    EscrowTermsLocked,
+    #[msg("Minted collections must be revealed with a final seed before closing")]
+    RevealPending,
+    #[msg("Nothing escrowed to release")]
+    NothingToRelease,
+    #[msg("Batch is larger than the active tree's remaining capacity")]
+    BatchExceedsTreeCapacity,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn auction(start_time: i64) -> PricingMode {
+        PricingMode::DutchAuction {
+            start_price: 1_000,
+            floor_price: 400,
+            start_time,
+            decay_interval: 10,
+            decay_step: 50,
+            rebates_enabled: true,
+        }
+    }
+
+    fn hash_pair(a: [u8; 32], b: [u8; 32]) -> [u8; 32] {
+        if a <= b {
+            keccak::hashv(&[&a, &b]).to_bytes()
+        } else {
+            keccak::hashv(&[&b, &a]).to_bytes()
+        }
+    }
+
+    #[test]
+    fn dutch_auction_price_decays_in_steps() {
+        assert_eq!(dutch_auction_price(auction(100), 100), 1_000);
+        assert_eq!(dutch_auction_price(auction(100), 109), 1_000);
+        assert_eq!(dutch_auction_price(auction(100), 110), 950);
+        assert_eq!(dutch_auction_price(auction(100), 129), 900);
+    }
+
+    #[test]
+    fn dutch_auction_price_clamps_to_floor() {
+        assert_eq!(dutch_auction_price(auction(100), 210), 450);
+        assert_eq!(dutch_auction_price(auction(100), 220), 400);
+        assert_eq!(dutch_auction_price(auction(100), 10_000), 400);
+        assert_eq!(dutch_auction_price(auction(100), i64::MAX), 400);
+    }
+
+    #[test]
+    fn dutch_auction_price_before_start_is_start_price() {
+        assert_eq!(dutch_auction_price(auction(100), 50), 1_000);
+        assert_eq!(dutch_auction_price(auction(100), i64::MIN), 1_000);
+        assert_eq!(dutch_auction_price(auction(i64::MAX), 0), 1_000);
+    }
+
+    #[test]
+    fn dutch_auction_price_is_zero_for_other_modes() {
+        assert_eq!(dutch_auction_price(PricingMode::Fixed, 100), 0);
+    }
+
+    #[test]
+    fn auction_rebate_is_the_net_share_of_the_overpayment() {
+        let units = auction_clearing_units(1_000, 1_000, 1);
+        assert_eq!(auction_rebate(1_000, units, 400), 600);
+
+        // 10% fees: the treasury only holds 900 and rebates its share
+        let units = auction_clearing_units(1_000, 900, 1);
+        assert_eq!(auction_rebate(900, units, 400), 540);
+        assert_eq!(auction_rebate(900, units, 1_000), 0);
+
+        let units = auction_clearing_units(1_900, 1_710, 2);
+        assert_eq!(auction_rebate(1_710, units, 900), 90);
+        assert_eq!(auction_clearing_units(0, 0, 0), 0);
+    }
+
+    #[test]
+    fn allowlist_proof_verifies_members_only() {
+        let (a, b, c) = ([1u8; 32], [2u8; 32], [3u8; 32]);
+        let ab = hash_pair(a, b);
+        let root = hash_pair(ab, c);
+
+        assert!(verify_allowlist_proof(&[b, c], root, a));
+        assert!(verify_allowlist_proof(&[a, c], root, b));
+        assert!(verify_allowlist_proof(&[ab], root, c));
+        assert!(!verify_allowlist_proof(&[b, c], root, [4u8; 32]));
+        assert!(!verify_allowlist_proof(&[c], root, a));
+        assert!(!verify_allowlist_proof(&[], root, a));
+    }
+
+    #[test]
+    fn allowlist_proof_single_leaf_root() {
+        let leaf = [7u8; 32];
+        assert!(verify_allowlist_proof(&[], leaf, leaf));
+    }
+
+    #[test]
+    fn item_name_fits_leaves_room_for_the_mystery_suffix() {
+        assert!(item_name_fits("Analos Apes", 10_000)); // "Analos Apes Mystery #9999"
+        assert!(item_name_fits(&"a".repeat(21), 10));
+        assert!(!item_name_fits(&"a".repeat(22), 10));
+        assert!(!item_name_fits(&"a".repeat(21), 11));
+    }
+
+    #[test]
+    fn revealed_uri_suffix_len_covers_the_last_index() {
+        assert_eq!(revealed_uri_suffix_len(1), "/0.json".len());
+        assert_eq!(revealed_uri_suffix_len(10), "/9.json".len());
+        assert_eq!(revealed_uri_suffix_len(11), "/10.json".len());
+        assert_eq!(revealed_uri_suffix_len(10_000), "/9999.json".len());
+    }
+
+    #[test]
+    fn merkle_tree_account_size_matches_spl_account_compression() {
+        let params = |max_depth, max_buffer_size, canopy_depth| TreeParams {
+            max_depth,
+            max_buffer_size,
+            canopy_depth,
+        };
+        assert_eq!(merkle_tree_account_size(&params(3, 8, 0)), 1_304);
+        assert_eq!(merkle_tree_account_size(&params(14, 64, 0)), 31_800);
+        assert_eq!(merkle_tree_account_size(&params(14, 64, 10)), 97_272);
+    }
+
+    #[test]
+    fn linear_curve_price() {
+        let curve = PricingMode::LinearCurve {
+            start_price: 100,
+            price_increment: 10,
+        };
+        assert_eq!(bonding_curve_price(curve, 0).unwrap(), 100);
+        assert_eq!(bonding_curve_price(curve, 5).unwrap(), 150);
+    }
+
+    #[test]
+    fn linear_curve_price_overflow_errors() {
+        let curve = PricingMode::LinearCurve {
+            start_price: u64::MAX,
+            price_increment: 1,
+        };
+        assert_eq!(bonding_curve_price(curve, 0).unwrap(), u64::MAX);
+        assert!(bonding_curve_price(curve, 1).is_err());
+
+        let steep = PricingMode::LinearCurve {
+            start_price: 0,
+            price_increment: u64::MAX,
+        };
+        assert!(bonding_curve_price(steep, u64::MAX).is_err());
+    }
+
+    #[test]
+    fn exponential_curve_price() {
+        let curve = PricingMode::ExponentialCurve {
+            start_price: 1_000_000,
+            growth_basis_points: 1_000,
+        };
+        assert_eq!(bonding_curve_price(curve, 0).unwrap(), 1_000_000);
+        assert_eq!(bonding_curve_price(curve, 1).unwrap(), 1_100_000);
+        assert_eq!(bonding_curve_price(curve, 2).unwrap(), 1_210_000);
+
+        let flat = PricingMode::ExponentialCurve {
+            start_price: 1_000_000,
+            growth_basis_points: 0,
+        };
+        assert_eq!(bonding_curve_price(flat, 10_000).unwrap(), 1_000_000);
+    }
+
+    #[test]
+    fn exponential_curve_price_overflow_errors() {
+        let curve = PricingMode::ExponentialCurve {
+            start_price: 1_000_000,
+            growth_basis_points: 10_000,
+        };
+        assert!(bonding_curve_price(curve, 200).is_err());
+        assert!(bonding_curve_price(curve, u64::MAX).is_err());
+    }
+
+    #[test]
+    fn bonding_curve_price_rejects_other_modes() {
+        assert!(bonding_curve_price(PricingMode::Fixed, 0).is_err());
+    }
+
+    #[test]
+    fn checked_pow_scaled_powers() {
+        assert_eq!(checked_pow_scaled(2 * CURVE_SCALE, 0), Some(CURVE_SCALE));
+        assert_eq!(checked_pow_scaled(2 * CURVE_SCALE, 1), Some(2 * CURVE_SCALE));
+        assert_eq!(checked_pow_scaled(2 * CURVE_SCALE, 10), Some(1_024 * CURVE_SCALE));
+        assert_eq!(checked_pow_scaled(CURVE_SCALE, 1_000_000), Some(CURVE_SCALE));
+    }
+
+    #[test]
+    fn checked_pow_scaled_overflow_is_none() {
+        assert_eq!(checked_pow_scaled(2 * CURVE_SCALE, 128), None);
+        assert_eq!(checked_pow_scaled(u128::MAX, 2), None);
+    }
 }
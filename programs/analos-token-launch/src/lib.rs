@@ -101,6 +101,7 @@ pub mod analos_token_launch {
         config.token_name = token_name.clone();
         config.token_symbol = token_symbol.clone();
         config.created_at = Clock::get()?.unix_timestamp;
+        config.pending_authority = None;
         
         emit!(TokenLaunchInitializedEvent {
             nft_collection: ctx.accounts.nft_collection_config.key(),
@@ -119,6 +120,9 @@ pub mod analos_token_launch {
     }
 
     /// Mint tokens when an NFT is minted (called via CPI from NFT Launchpad)
+    ///
+    /// The linked collection config PDA must sign, so escrow funding only
+    /// happens atomically with an NFT mint.
     pub fn mint_tokens_for_nft(
         ctx: Context<MintTokensForNFT>,
         nft_mint: Pubkey,
@@ -524,6 +528,52 @@ pub mod analos_token_launch {
         
         Ok(())
     }
+
+    /// Propose a new token launch authority, or cancel with `None`
+    pub fn propose_authority(
+        ctx: Context<ProposeAuthority>,
+        new_authority: Option<Pubkey>,
+    ) -> Result<()> {
+        let config = &mut ctx.accounts.token_launch_config;
+        
+        config.pending_authority = new_authority;
+        
+        emit!(AuthorityProposedEvent {
+            token_launch_config: config.key(),
+            authority: config.authority,
+            pending_authority: new_authority,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        msg!("Authority transfer proposed: {:?}", new_authority);
+        
+        Ok(())
+    }
+
+    /// Accept a proposed token launch authority transfer
+    pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+        let config = &mut ctx.accounts.token_launch_config;
+        
+        require!(
+            config.pending_authority == Some(ctx.accounts.new_authority.key()),
+            ErrorCode::Unauthorized
+        );
+        
+        let previous_authority = config.authority;
+        config.authority = ctx.accounts.new_authority.key();
+        config.pending_authority = None;
+        
+        emit!(AuthorityTransferredEvent {
+            token_launch_config: config.key(),
+            previous_authority,
+            new_authority: config.authority,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        msg!("Authority transferred from {} to {}", previous_authority, config.authority);
+        
+        Ok(())
+    }
 }
 
 // ========== ACCOUNT CONTEXTS ==========
@@ -575,10 +625,14 @@ pub struct MintTokensForNFT<'info> {
     )]
     pub token_launch_config: Account<'info, TokenLaunchConfig>,
 
-    #[account(mut)]
+    /// NFT Launchpad collection config PDA, signing via CPI
+    #[account(address = token_launch_config.nft_collection_config)]
+    pub nft_collection_config: Signer<'info>,
+
+    #[account(mut, address = token_launch_config.token_mint)]
     pub token_mint: Account<'info, Mint>,
 
-    #[account(mut)]
+    #[account(mut, address = token_launch_config.token_escrow)]
     pub token_escrow: Account<'info, TokenAccount>,
 
     pub token_program: Program<'info, Token>,
@@ -750,6 +804,9 @@ pub struct TokenLaunchConfig {
     // NEW: Trading fee accumulation
     pub trading_fees_collected: u64,               // Fees from bonding curve trades
     pub trading_fees_claimed: u64,                 // Fees claimed by creator
+    
+    // Two-step authority transfer
+    pub pending_authority: Option<Pubkey>,         // Proposed authority awaiting acceptance
 }
 
 #[account]
@@ -868,6 +925,22 @@ pub struct TradingFeesClaimedEvent {
     pub timestamp: i64,
 }
 
+#[event]
+pub struct AuthorityProposedEvent {
+    pub token_launch_config: Pubkey,
+    pub authority: Pubkey,
+    pub pending_authority: Option<Pubkey>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AuthorityTransferredEvent {
+    pub token_launch_config: Pubkey,
+    pub previous_authority: Pubkey,
+    pub new_authority: Pubkey,
+    pub timestamp: i64,
+}
+
 // ========== ERRORS ==========
 
 #[error_code]
@@ -906,6 +979,8 @@ pub enum ErrorCode {
     PrebuyLimitExceeded,
     #[msg("Insufficient trading fees")]
     InsufficientTradingFees,
+    #[msg("Unauthorized")]
+    Unauthorized,
 }
 
 // ========== ACCOUNT CONTEXTS (NEW) ==========
@@ -952,3 +1027,27 @@ pub struct ClaimTradingFees<'info> {
     pub authority: Signer<'info>,
 }
 
+#[derive(Accounts)]
+pub struct ProposeAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [b"token_launch_config", token_launch_config.nft_collection_config.as_ref()],
+        bump,
+        has_one = authority,
+    )]
+    pub token_launch_config: Account<'info, TokenLaunchConfig>,
+
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [b"token_launch_config", token_launch_config.nft_collection_config.as_ref()],
+        bump,
+    )]
+    pub token_launch_config: Account<'info, TokenLaunchConfig>,
+
+    pub new_authority: Signer<'info>,
+}